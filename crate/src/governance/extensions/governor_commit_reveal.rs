@@ -0,0 +1,317 @@
+pub use crate::{
+    governance::extensions::{
+        governor_commit_reveal,
+        governor_commit_reveal::Internal as _,
+    },
+    traits::governance::extensions::commit_reveal::*,
+};
+
+use crate::{
+    governor::{
+        self,
+        modules::{
+            counter::Counter,
+            voter::Voter,
+        },
+    },
+    traits::signature,
+};
+
+use crate::governance::governor::*;
+
+use ink::{
+    prelude::vec::Vec,
+    storage::traits::{
+        AutoStorableHint,
+        ManualKey,
+        Storable,
+        StorableHint,
+    },
+};
+use openbrush::{
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        BlockNumber,
+        Hash,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(GovernorCommitReveal);
+
+/// Governor commit reveal extension upgradeable storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// The number of blocks after the deadline of a proposal in which the votes can be revealed
+    pub reveal_period: BlockNumber,
+    /// Map every account and proposal to the commitment that has not been revealed yet
+    pub commitments: Mapping<(AccountId, ProposalId), Hash>,
+    pub _reserved: Option<()>,
+}
+
+impl<T, C, V> GovernorCommitReveal for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn reveal_period(&self) -> BlockNumber {
+        self.data::<Data>().reveal_period
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn set_reveal_period(
+        &mut self,
+        new_reveal_period: BlockNumber,
+    ) -> Result<(), GovernorError> {
+        self._set_reveal_period(new_reveal_period);
+        Ok(())
+    }
+
+    default fn vote_commitment(
+        &self,
+        proposal_id: ProposalId,
+        account: AccountId,
+    ) -> Option<Hash> {
+        self.data::<Data>().commitments.get(&(account, proposal_id))
+    }
+
+    default fn hash_vote(
+        &self,
+        proposal_id: ProposalId,
+        voter: AccountId,
+        support: u8,
+        salt: Hash,
+        params: Vec<u8>,
+    ) -> Hash {
+        Hash::from(signature::hash_payload(&(
+            proposal_id,
+            voter,
+            support,
+            salt,
+            params,
+        )))
+    }
+
+    default fn commit_vote(
+        &mut self,
+        proposal_id: ProposalId,
+        commitment: Hash,
+    ) -> Result<(), GovernorError> {
+        match self.state(proposal_id)? {
+            ProposalState::Active => {}
+            _ => return Err(GovernorError::ProposalNotActive),
+        }
+
+        let voter = Self::env().caller();
+        self.data::<Data>()
+            .commitments
+            .insert(&(voter, proposal_id), &commitment);
+
+        self._emit_vote_committed(voter, proposal_id);
+
+        Ok(())
+    }
+
+    default fn reveal_vote(
+        &mut self,
+        proposal_id: ProposalId,
+        support: u8,
+        salt: Hash,
+        params: Vec<u8>,
+    ) -> Result<u64, GovernorError> {
+        self._reveal_vote(&proposal_id, &Self::env().caller(), support, &salt, &params)
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_vote_committed(&self, _voter: AccountId, _proposal_id: ProposalId);
+
+    fn _emit_reveal_period_set(
+        &self,
+        _old_reveal_period: BlockNumber,
+        _new_reveal_period: BlockNumber,
+    );
+
+    fn _init_with_reveal_period(&mut self, reveal_period: BlockNumber);
+
+    fn _set_reveal_period(&mut self, new_reveal_period: BlockNumber);
+
+    /// Overridden version of the Governor state function with added support for the Revealing
+    /// status.
+    ///
+    /// Note: The user must override the `state` message of Governor to call this method. The
+    /// votes cast directly are public, so the user should also override the
+    /// `_cast_vote_with_params` method of governor::Internal to return `VoteMustBeCommitted`.
+    fn _commit_reveal_state(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalState, GovernorError>;
+
+    /// Check that the proposal is revealing and that the vote matches the commitment of the
+    /// account, then count the vote with the weight of the account at the proposal snapshot.
+    ///
+    /// Emits a VoteCast event or VoteCastWithParams event depending on the length of params.
+    fn _reveal_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+        support: u8,
+        salt: &Hash,
+        params: &[u8],
+    ) -> Result<u64, GovernorError>;
+}
+
+impl<T, C, V> Internal for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn _emit_vote_committed(&self, _voter: AccountId, _proposal_id: ProposalId) {}
+
+    default fn _emit_reveal_period_set(
+        &self,
+        _old_reveal_period: BlockNumber,
+        _new_reveal_period: BlockNumber,
+    ) {
+    }
+
+    default fn _init_with_reveal_period(&mut self, reveal_period: BlockNumber) {
+        self._set_reveal_period(reveal_period);
+    }
+
+    default fn _set_reveal_period(&mut self, new_reveal_period: BlockNumber) {
+        let old_reveal_period = self.data::<Data>().reveal_period;
+        self._emit_reveal_period_set(old_reveal_period, new_reveal_period);
+
+        self.data::<Data>().reveal_period = new_reveal_period;
+    }
+
+    default fn _commit_reveal_state(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalState, GovernorError> {
+        let proposal = self
+            .data::<governor::Data<C, V>>()
+            .proposals
+            .get(proposal_id)
+            .ok_or(GovernorError::ProposalNotFound)?;
+
+        if !proposal.executed && !proposal.canceled {
+            let deadline = self.proposal_deadline(*proposal_id)?;
+            let reveal_end = deadline.saturating_add(self.data::<Data>().reveal_period);
+            let block_number = Self::env().block_number();
+
+            // The outcome of the proposal is not known until the votes are revealed.
+            if deadline < block_number && block_number <= reveal_end {
+                return Ok(ProposalState::Revealing)
+            }
+        }
+
+        governor::Internal::_state(self, proposal_id)
+    }
+
+    default fn _reveal_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+        support: u8,
+        salt: &Hash,
+        params: &[u8],
+    ) -> Result<u64, GovernorError> {
+        match self.state(*proposal_id)? {
+            ProposalState::Revealing => {}
+            _ => return Err(GovernorError::ProposalNotRevealing),
+        }
+
+        let commitment = self
+            .data::<Data>()
+            .commitments
+            .get(&(*account, *proposal_id))
+            .ok_or(GovernorError::NoCommitment)?;
+
+        if commitment
+            != self.hash_vote(*proposal_id, *account, support, *salt, params.to_vec())
+        {
+            return Err(GovernorError::InvalidReveal)
+        }
+
+        self.data::<Data>()
+            .commitments
+            .remove(&(*account, *proposal_id));
+
+        let snapshot = self.proposal_snapshot(*proposal_id)?;
+        let weight = governor::Internal::_get_votes(self, account, snapshot, params)?;
+
+        governor::Internal::_count_vote(
+            self,
+            proposal_id,
+            account,
+            support,
+            weight,
+            params,
+        )?;
+
+        governor::Internal::_after_count_vote(self, proposal_id)?;
+
+        if params.is_empty() {
+            governor::Internal::_emit_vote_cast(
+                self,
+                *account,
+                *proposal_id,
+                support,
+                weight,
+                String::new(),
+            );
+        } else {
+            governor::Internal::_emit_vote_cast_with_params(
+                self,
+                *account,
+                *proposal_id,
+                support,
+                weight,
+                String::new(),
+                params.to_vec(),
+            );
+        }
+
+        Ok(weight)
+    }
+}
@@ -0,0 +1,218 @@
+pub use crate::{
+    governance::extensions::{
+        governor_prevent_late_quorum,
+        governor_prevent_late_quorum::Internal as _,
+    },
+    traits::governance::extensions::prevent_late_quorum::*,
+};
+
+use crate::governor::{
+    self,
+    modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+
+use crate::governance::governor::*;
+
+use ink::storage::traits::{
+    AutoStorableHint,
+    ManualKey,
+    Storable,
+    StorableHint,
+};
+use openbrush::{
+    modifiers,
+    storage::Mapping,
+    traits::{
+        BlockNumber,
+        OccupiedStorage,
+        Storage,
+    },
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(GovernorPreventLateQuorum);
+
+/// Governor prevent late quorum extension upgradeable storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// The number of blocks that are required to pass from the time a proposal reaches quorum
+    /// until its voting period ends
+    pub vote_extension: BlockNumber,
+    /// Map every proposal that reached quorum to its extended deadline
+    pub extended_deadlines: Mapping<ProposalId, BlockNumber>,
+    pub _reserved: Option<()>,
+}
+
+impl<T, C, V> GovernorPreventLateQuorum for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn late_quorum_vote_extension(&self) -> BlockNumber {
+        self.data::<Data>().vote_extension
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn set_late_quorum_vote_extension(
+        &mut self,
+        new_vote_extension: BlockNumber,
+    ) -> Result<(), GovernorError> {
+        self._set_late_quorum_vote_extension(new_vote_extension);
+        Ok(())
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_proposal_extended(
+        &self,
+        _proposal_id: ProposalId,
+        _extended_deadline: BlockNumber,
+    );
+
+    fn _emit_late_quorum_vote_extension_set(
+        &self,
+        _old_vote_extension: BlockNumber,
+        _new_vote_extension: BlockNumber,
+    );
+
+    fn _init_with_vote_extension(&mut self, vote_extension: BlockNumber);
+
+    fn _set_late_quorum_vote_extension(&mut self, new_vote_extension: BlockNumber);
+
+    /// Overridden version of the Governor proposal_deadline function that takes into account the
+    /// deadline extension of the proposals that reached quorum late.
+    ///
+    /// Note: The user must override the `proposal_deadline` message of Governor to call this
+    /// method.
+    fn _late_quorum_proposal_deadline(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<BlockNumber, GovernorError>;
+
+    /// Extend the deadline of a proposal the first time a vote makes it reach quorum, if the
+    /// remaining voting period is shorter than the vote extension.
+    ///
+    /// Emits a ProposalExtended event.
+    ///
+    /// Note: The user must override the `_after_count_vote` method of governor::Internal to call
+    /// this method.
+    fn _prevent_late_quorum(
+        &mut self,
+        proposal_id: &ProposalId,
+    ) -> Result<(), GovernorError>;
+}
+
+impl<T, C, V> Internal for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn _emit_proposal_extended(
+        &self,
+        _proposal_id: ProposalId,
+        _extended_deadline: BlockNumber,
+    ) {
+    }
+
+    default fn _emit_late_quorum_vote_extension_set(
+        &self,
+        _old_vote_extension: BlockNumber,
+        _new_vote_extension: BlockNumber,
+    ) {
+    }
+
+    default fn _init_with_vote_extension(&mut self, vote_extension: BlockNumber) {
+        self._set_late_quorum_vote_extension(vote_extension);
+    }
+
+    default fn _set_late_quorum_vote_extension(
+        &mut self,
+        new_vote_extension: BlockNumber,
+    ) {
+        let old_vote_extension = self.data::<Data>().vote_extension;
+        self._emit_late_quorum_vote_extension_set(old_vote_extension, new_vote_extension);
+
+        self.data::<Data>().vote_extension = new_vote_extension;
+    }
+
+    default fn _late_quorum_proposal_deadline(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<BlockNumber, GovernorError> {
+        let deadline = governor::Internal::_proposal_deadline(self, proposal_id)?;
+        let extended_deadline = self
+            .data::<Data>()
+            .extended_deadlines
+            .get(proposal_id)
+            .unwrap_or_default();
+
+        Ok(deadline.max(extended_deadline))
+    }
+
+    default fn _prevent_late_quorum(
+        &mut self,
+        proposal_id: &ProposalId,
+    ) -> Result<(), GovernorError> {
+        // The deadline is extended only the first time the quorum is reached.
+        if self
+            .data::<Data>()
+            .extended_deadlines
+            .get(proposal_id)
+            .is_some()
+            || !governor::Internal::_quorum_reached(self, proposal_id)
+        {
+            return Ok(())
+        }
+
+        let extended_deadline = Self::env()
+            .block_number()
+            .saturating_add(self.data::<Data>().vote_extension);
+
+        if extended_deadline > governor::Internal::_proposal_deadline(self, proposal_id)?
+        {
+            self._emit_proposal_extended(*proposal_id, extended_deadline);
+        }
+
+        self.data::<Data>()
+            .extended_deadlines
+            .insert(proposal_id, &extended_deadline);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+pub use crate::{
+    governance::extensions::{
+        governor_proposal_deposit,
+        governor_proposal_deposit::Internal as _,
+    },
+    traits::governance::extensions::proposal_deposit::*,
+};
+
+use crate::governor::{
+    self,
+    modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+
+use crate::governance::governor::*;
+
+use ink::storage::traits::{
+    AutoStorableHint,
+    ManualKey,
+    Storable,
+    StorableHint,
+};
+use openbrush::{
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(GovernorProposalDeposit);
+
+/// Governor proposal deposit extension upgradeable storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// The minimum value that must be transferred to create a proposal
+    pub deposit_amount: Balance,
+    /// Map every proposal to its deposit until it is released
+    pub deposits: Mapping<ProposalId, Deposit>,
+    pub _reserved: Option<()>,
+}
+
+impl<T, C, V> GovernorProposalDeposit for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn deposit_amount(&self) -> Balance {
+        self.data::<Data>().deposit_amount
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn set_deposit_amount(
+        &mut self,
+        new_deposit_amount: Balance,
+    ) -> Result<(), GovernorError> {
+        self._set_deposit_amount(new_deposit_amount);
+        Ok(())
+    }
+
+    default fn proposal_deposit(&self, proposal_id: ProposalId) -> Option<Deposit> {
+        self.data::<Data>().deposits.get(&proposal_id)
+    }
+
+    default fn propose_with_deposit(
+        &mut self,
+        proposal: Proposal,
+        description: String,
+    ) -> Result<ProposalId, GovernorError> {
+        self.propose(proposal, description)
+    }
+
+    default fn release_deposit(
+        &mut self,
+        proposal_id: ProposalId,
+    ) -> Result<(), GovernorError> {
+        self._release_deposit(&proposal_id)
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_deposit_amount_set(
+        &self,
+        _old_deposit_amount: Balance,
+        _new_deposit_amount: Balance,
+    );
+
+    fn _emit_deposit_refunded(
+        &self,
+        _proposal_id: ProposalId,
+        _depositor: AccountId,
+        _amount: Balance,
+    );
+
+    fn _emit_deposit_slashed(
+        &self,
+        _proposal_id: ProposalId,
+        _depositor: AccountId,
+        _amount: Balance,
+    );
+
+    fn _init_with_deposit_amount(&mut self, deposit_amount: Balance);
+
+    fn _set_deposit_amount(&mut self, new_deposit_amount: Balance);
+
+    /// Store the value transferred to create a proposal as its deposit, the proposal is reverted
+    /// if the value is less than the deposit amount. The `propose` message of Governor is not
+    /// payable, so a proposal can be created only through `propose_with_deposit` while the
+    /// deposit amount is not zero.
+    ///
+    /// Note: The user must override the `_after_propose` method of governor::Internal to call this
+    /// method.
+    fn _store_deposit(
+        &mut self,
+        depositor: AccountId,
+        proposal_id: &ProposalId,
+    ) -> Result<(), GovernorError>;
+
+    /// Refund the deposit of a proposal whose voting is over to the depositor, or slash it if the
+    /// proposal was vetoed.
+    ///
+    /// Emits a DepositRefunded or a DepositSlashed event.
+    fn _release_deposit(&mut self, proposal_id: &ProposalId)
+        -> Result<(), GovernorError>;
+}
+
+impl<T, C, V> Internal for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn _emit_deposit_amount_set(
+        &self,
+        _old_deposit_amount: Balance,
+        _new_deposit_amount: Balance,
+    ) {
+    }
+
+    default fn _emit_deposit_refunded(
+        &self,
+        _proposal_id: ProposalId,
+        _depositor: AccountId,
+        _amount: Balance,
+    ) {
+    }
+
+    default fn _emit_deposit_slashed(
+        &self,
+        _proposal_id: ProposalId,
+        _depositor: AccountId,
+        _amount: Balance,
+    ) {
+    }
+
+    default fn _init_with_deposit_amount(&mut self, deposit_amount: Balance) {
+        self._set_deposit_amount(deposit_amount);
+    }
+
+    default fn _set_deposit_amount(&mut self, new_deposit_amount: Balance) {
+        let old_deposit_amount = self.data::<Data>().deposit_amount;
+        self._emit_deposit_amount_set(old_deposit_amount, new_deposit_amount);
+
+        self.data::<Data>().deposit_amount = new_deposit_amount;
+    }
+
+    default fn _store_deposit(
+        &mut self,
+        depositor: AccountId,
+        proposal_id: &ProposalId,
+    ) -> Result<(), GovernorError> {
+        let amount = Self::env().transferred_value();
+        if amount < self.data::<Data>().deposit_amount {
+            return Err(GovernorError::InsufficientDeposit)
+        }
+
+        if amount > 0 {
+            let deposit = Deposit { depositor, amount };
+            self.data::<Data>().deposits.insert(proposal_id, &deposit);
+        }
+
+        Ok(())
+    }
+
+    default fn _release_deposit(
+        &mut self,
+        proposal_id: &ProposalId,
+    ) -> Result<(), GovernorError> {
+        let deposit = self
+            .data::<Data>()
+            .deposits
+            .get(proposal_id)
+            .ok_or(GovernorError::NoDeposit)?;
+
+        match self.state(*proposal_id)? {
+            ProposalState::Pending | ProposalState::Active | ProposalState::Revealing => {
+                return Err(GovernorError::ProposalNotFinished)
+            }
+            _ => {}
+        }
+
+        self.data::<Data>().deposits.remove(proposal_id);
+
+        // A slashed deposit is kept by the governor.
+        if governor::Internal::_vote_vetoed(self, proposal_id) {
+            self._emit_deposit_slashed(*proposal_id, deposit.depositor, deposit.amount);
+            return Ok(())
+        }
+
+        Self::env()
+            .transfer(deposit.depositor, deposit.amount)
+            .map_err(|_| GovernorError::DepositTransferFailed)?;
+        self._emit_deposit_refunded(*proposal_id, deposit.depositor, deposit.amount);
+
+        Ok(())
+    }
+}
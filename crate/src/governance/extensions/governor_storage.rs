@@ -0,0 +1,217 @@
+pub use crate::{
+    governance::extensions::{
+        governor_storage,
+        governor_storage::Internal as _,
+    },
+    traits::governance::extensions::storage::*,
+};
+
+use crate::governor::{
+    self,
+    modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+
+use crate::governance::governor::*;
+
+use ink::{
+    prelude::vec::Vec,
+    storage::traits::{
+        AutoStorableHint,
+        ManualKey,
+        Storable,
+        StorableHint,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Hash,
+        OccupiedStorage,
+        Storage,
+    },
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(GovernorStorage);
+
+/// Governor storage extension upgradeable storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Map the index of creation of every proposal to its id
+    pub proposal_ids: Mapping<u32, ProposalId>,
+    /// The number of stored proposals
+    pub proposal_count: u32,
+    /// Map every proposal to its details
+    pub proposal_details: Mapping<ProposalId, ProposalDetails>,
+    pub _reserved: Option<()>,
+}
+
+impl<T, C, V> GovernorStorage for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn proposal_count(&self) -> u32 {
+        self.data::<Data>().proposal_count
+    }
+
+    default fn proposal_details(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Option<ProposalDetails> {
+        self.data::<Data>().proposal_details.get(&proposal_id)
+    }
+
+    default fn proposals(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(ProposalId, ProposalDetails)> {
+        let end = offset
+            .saturating_add(limit)
+            .min(self.data::<Data>().proposal_count);
+
+        (offset..end)
+            .filter_map(|index| {
+                let proposal_id = self.data::<Data>().proposal_ids.get(&index)?;
+                let details = self.data::<Data>().proposal_details.get(&proposal_id)?;
+                Some((proposal_id, details))
+            })
+            .collect()
+    }
+
+    default fn queue_by_id(
+        &mut self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalId, GovernorError> {
+        let details = self._proposal_details(&proposal_id)?;
+        self._queue(details.proposal, details.description_hash)
+    }
+
+    default fn execute_by_id(
+        &mut self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalId, GovernorError> {
+        let details = self._proposal_details(&proposal_id)?;
+        self.execute(details.proposal, details.description_hash)
+    }
+
+    default fn cancel_by_id(
+        &mut self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalId, GovernorError> {
+        let details = self._proposal_details(&proposal_id)?;
+        self.cancel(details.proposal, details.description_hash)
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    /// Store the details of a newly created proposal and add it to the index.
+    ///
+    /// Note: The user must override the `_after_propose` method of governor::Internal to call this
+    /// method.
+    fn _store_proposal(
+        &mut self,
+        proposer: AccountId,
+        proposal_id: &ProposalId,
+        proposal: &Proposal,
+        description_hash: &Hash,
+    );
+
+    /// Returns the details of a stored proposal.
+    fn _proposal_details(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalDetails, GovernorError>;
+
+    /// Queue mechanism used by `queue_by_id`. By default there is no queueing mechanism.
+    ///
+    /// Note: The user must override this method to call the `queue` message of the extension that
+    /// provides it (e.g. GovernorTimelockControl).
+    fn _queue(
+        &mut self,
+        proposal: Proposal,
+        description_hash: Hash,
+    ) -> Result<ProposalId, GovernorError>;
+}
+
+impl<T, C, V> Internal for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn _store_proposal(
+        &mut self,
+        proposer: AccountId,
+        proposal_id: &ProposalId,
+        proposal: &Proposal,
+        description_hash: &Hash,
+    ) {
+        let index = self.data::<Data>().proposal_count;
+
+        self.data::<Data>().proposal_ids.insert(&index, proposal_id);
+        self.data::<Data>().proposal_details.insert(
+            proposal_id,
+            &ProposalDetails {
+                proposer,
+                proposal: proposal.clone(),
+                description_hash: *description_hash,
+            },
+        );
+        self.data::<Data>().proposal_count = index + 1;
+    }
+
+    default fn _proposal_details(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalDetails, GovernorError> {
+        self.data::<Data>()
+            .proposal_details
+            .get(proposal_id)
+            .ok_or(GovernorError::ProposalNotFound)
+    }
+
+    default fn _queue(
+        &mut self,
+        _proposal: Proposal,
+        _description_hash: Hash,
+    ) -> Result<ProposalId, GovernorError> {
+        Err(GovernorError::QueueNotSupported)
+    }
+}
@@ -0,0 +1,346 @@
+pub use crate::{
+    governance::extensions::{
+        governor_timelock_control,
+        governor_timelock_control::Internal as _,
+    },
+    traits::governance::{
+        extensions::timelock_control::*,
+        utils::timelock_controller::*,
+    },
+};
+
+use crate::governor::{
+    self,
+    modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+
+use crate::governance::governor::*;
+
+use ink::{
+    env::CallFlags,
+    storage::traits::{
+        AutoStorableHint,
+        ManualKey,
+        Storable,
+        StorableHint,
+    },
+};
+use openbrush::{
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Hash,
+        OccupiedStorage,
+        Storage,
+        Timestamp,
+        ZERO_ADDRESS,
+    },
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(GovernorTimelockControl);
+
+/// Governor timelock control extension upgradeable storage struct
+#[derive(Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// The timelock contract through which the proposals are executed
+    pub timelock: AccountId,
+    /// Map every queued proposal to the id of the operation scheduled in the timelock
+    pub timelock_ids: Mapping<ProposalId, OperationId>,
+    /// Map every queued proposal to the timestamp at which it becomes executable
+    pub proposal_etas: Mapping<ProposalId, Timestamp>,
+    /// Time after the eta in which a queued proposal can be executed before expiring (None if the
+    /// queued proposals never expire)
+    pub grace_period: Option<Timestamp>,
+    pub _reserved: Option<()>,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Data {
+            timelock: ZERO_ADDRESS.into(),
+            timelock_ids: Default::default(),
+            proposal_etas: Default::default(),
+            grace_period: Default::default(),
+            _reserved: Default::default(),
+        }
+    }
+}
+
+impl<T, C, V> GovernorTimelockControl for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn timelock(&self) -> AccountId {
+        self.data::<Data>().timelock
+    }
+
+    default fn proposal_eta(&self, proposal_id: ProposalId) -> Option<Timestamp> {
+        self.data::<Data>().proposal_etas.get(&proposal_id)
+    }
+
+    default fn queue(
+        &mut self,
+        proposal: Proposal,
+        description_hash: Hash,
+    ) -> Result<ProposalId, GovernorError> {
+        let proposal_id =
+            governor::Internal::_hash_proposal(self, &proposal, &description_hash);
+
+        if self.state(proposal_id)? != ProposalState::Succeeded {
+            return Err(GovernorError::ProposalNotSuccessful)
+        }
+
+        let timelock = self.data::<Data>().timelock;
+        let delay = TimelockControllerRef::get_min_delay(&timelock);
+
+        // The proposal id is unique so it is used as salt of the operation.
+        let timelock_id = TimelockControllerRef::hash_operation_batch(
+            &timelock,
+            proposal.transactions.clone(),
+            None,
+            proposal_id,
+        );
+        TimelockControllerRef::schedule_batch(
+            &timelock,
+            proposal.transactions,
+            None,
+            proposal_id,
+            delay,
+        )
+        .map_err(GovernorError::TimelockControllerError)?;
+
+        // The timestamp set by the timelock is the eta of the proposal.
+        let eta = TimelockControllerRef::get_timestamp(&timelock, timelock_id);
+
+        self.data::<Data>()
+            .timelock_ids
+            .insert(&proposal_id, &timelock_id);
+        self.data::<Data>().proposal_etas.insert(&proposal_id, &eta);
+
+        self._emit_proposal_queued(proposal_id, eta);
+
+        Ok(proposal_id)
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn update_timelock(
+        &mut self,
+        new_timelock: AccountId,
+    ) -> Result<(), GovernorError> {
+        self._update_timelock(new_timelock);
+        Ok(())
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_proposal_queued(&self, _proposal_id: ProposalId, _eta: Timestamp);
+
+    fn _emit_timelock_change(&self, _old_timelock: AccountId, _new_timelock: AccountId);
+
+    fn _init_with_timelock(
+        &mut self,
+        timelock: AccountId,
+        grace_period: Option<Timestamp>,
+    );
+
+    fn _update_timelock(&mut self, new_timelock: AccountId);
+
+    /// Overridden version of the Governor state function with added support for the Queued and
+    /// Expired status.
+    ///
+    /// Note: The user must override the `state` message of Governor to call this method.
+    fn _timelock_state(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalState, GovernorError>;
+
+    /// Overridden execute function that run the already queued proposal through the timelock.
+    ///
+    /// Note: The user must override the `_execute` method of governor::Internal to call this
+    /// method.
+    fn _timelock_execute(
+        &mut self,
+        proposal_id: &ProposalId,
+        proposal: &Proposal,
+    ) -> Result<(), GovernorError>;
+
+    /// Cancel the operation scheduled in the timelock for a queued proposal, if any.
+    ///
+    /// Note: The user must override the `_after_cancel` method of governor::Internal to call this
+    /// method.
+    fn _timelock_cancel(&mut self, proposal_id: &ProposalId)
+        -> Result<(), GovernorError>;
+
+    /// Address through which the governor executes action. In this case, the timelock.
+    ///
+    /// Note: The user must override the `_executor` method of governor::Internal to call this
+    /// method.
+    fn _timelock_executor(&self) -> AccountId;
+}
+
+impl<T, C, V> Internal for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn _emit_proposal_queued(&self, _proposal_id: ProposalId, _eta: Timestamp) {}
+
+    default fn _emit_timelock_change(
+        &self,
+        _old_timelock: AccountId,
+        _new_timelock: AccountId,
+    ) {
+    }
+
+    default fn _init_with_timelock(
+        &mut self,
+        timelock: AccountId,
+        grace_period: Option<Timestamp>,
+    ) {
+        self._update_timelock(timelock);
+        self.data::<Data>().grace_period = grace_period;
+    }
+
+    default fn _update_timelock(&mut self, new_timelock: AccountId) {
+        let old_timelock = self.data::<Data>().timelock;
+        self._emit_timelock_change(old_timelock, new_timelock);
+
+        self.data::<Data>().timelock = new_timelock;
+    }
+
+    default fn _timelock_state(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalState, GovernorError> {
+        let status = governor::Internal::_state(self, proposal_id)?;
+
+        if status != ProposalState::Succeeded {
+            return Ok(status)
+        }
+
+        let timelock_id = match self.data::<Data>().timelock_ids.get(proposal_id) {
+            Some(timelock_id) => timelock_id,
+            None => return Ok(status),
+        };
+
+        let timelock = self.data::<Data>().timelock;
+
+        if TimelockControllerRef::is_operation_done(&timelock, timelock_id) {
+            return Ok(ProposalState::Executed)
+        }
+
+        if TimelockControllerRef::is_operation_pending(&timelock, timelock_id) {
+            let eta = self
+                .data::<Data>()
+                .proposal_etas
+                .get(proposal_id)
+                .unwrap_or_default();
+
+            if let Some(grace_period) = self.data::<Data>().grace_period {
+                if Self::env().block_timestamp() > eta.saturating_add(grace_period) {
+                    return Ok(ProposalState::Expired)
+                }
+            }
+
+            return Ok(ProposalState::Queued)
+        }
+
+        Ok(ProposalState::Canceled)
+    }
+
+    default fn _timelock_execute(
+        &mut self,
+        proposal_id: &ProposalId,
+        proposal: &Proposal,
+    ) -> Result<(), GovernorError> {
+        let timelock = self.data::<Data>().timelock;
+        let transferred_value = proposal.transferred_value()?;
+
+        // Flush the state into storage before the cross call.
+        // Because during cross call the timelock can call this contract.
+        self.flush();
+        let result = TimelockControllerRef::execute_batch_builder(
+            &timelock,
+            proposal.transactions.clone(),
+            None,
+            *proposal_id,
+        )
+        .transferred_value(transferred_value)
+        .call_flags(CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+        .map_err(|_| GovernorError::CallRevertedWithoutMessage);
+
+        // Load the state of the contract after the cross call.
+        self.load();
+        governor::Internal::_emit_proposal_executed(self, *proposal_id);
+
+        let post_call = result?;
+
+        post_call?.map_err(GovernorError::TimelockControllerError)?;
+
+        self.data::<Data>().timelock_ids.remove(proposal_id);
+        self.data::<Data>().proposal_etas.remove(proposal_id);
+
+        Ok(())
+    }
+
+    default fn _timelock_cancel(
+        &mut self,
+        proposal_id: &ProposalId,
+    ) -> Result<(), GovernorError> {
+        if let Some(timelock_id) = self.data::<Data>().timelock_ids.get(proposal_id) {
+            let timelock = self.data::<Data>().timelock;
+
+            TimelockControllerRef::cancel(&timelock, timelock_id)
+                .map_err(GovernorError::TimelockControllerError)?;
+
+            self.data::<Data>().timelock_ids.remove(proposal_id);
+            self.data::<Data>().proposal_etas.remove(proposal_id);
+        }
+
+        Ok(())
+    }
+
+    default fn _timelock_executor(&self) -> AccountId {
+        self.data::<Data>().timelock
+    }
+}
@@ -0,0 +1,243 @@
+pub use crate::{
+    governance::extensions::{
+        governor_votes_quorum_fraction,
+        governor_votes_quorum_fraction::Internal as _,
+    },
+    traits::governance::extensions::votes_quorum_fraction::*,
+};
+
+use crate::governor::{
+    self,
+    modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+
+use crate::{
+    governance::governor::*,
+    traits::token::psp22::extensions::votes::Checkpoint,
+};
+
+use ink::{
+    prelude::vec::Vec,
+    storage::traits::{
+        AutoStorableHint,
+        ManualKey,
+        Storable,
+        StorableHint,
+    },
+};
+use openbrush::{
+    modifiers,
+    traits::{
+        BlockNumber,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(GovernorVotesQuorumFraction);
+
+/// Governor votes quorum fraction extension upgradeable storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// History of the quorum numerator (the `votes` field of each checkpoint is the numerator)
+    pub quorum_numerator_history: Vec<Checkpoint>,
+    pub _reserved: Option<()>,
+}
+
+impl<T, C, V> GovernorVotesQuorumFraction for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn quorum_numerator(&self) -> u64 {
+        self.data::<Data>()
+            .quorum_numerator_history
+            .last()
+            .map(|checkpoint| checkpoint.votes)
+            .unwrap_or_default()
+    }
+
+    default fn quorum_numerator_at(&self, block_number: BlockNumber) -> u64 {
+        self._quorum_numerator_at(block_number)
+    }
+
+    default fn quorum_denominator(&self) -> u64 {
+        self._quorum_denominator()
+    }
+
+    default fn quorum_at(&self, block_number: BlockNumber) -> Result<u64, GovernorError> {
+        self._quorum_fraction(block_number)
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn update_quorum_numerator(
+        &mut self,
+        new_quorum_numerator: u64,
+    ) -> Result<(), GovernorError> {
+        self._update_quorum_numerator(new_quorum_numerator)
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_quorum_numerator_updated(
+        &self,
+        _old_quorum_numerator: u64,
+        _new_quorum_numerator: u64,
+    );
+
+    fn _init_with_quorum_numerator(
+        &mut self,
+        quorum_numerator: u64,
+    ) -> Result<(), GovernorError>;
+
+    fn _update_quorum_numerator(
+        &mut self,
+        new_quorum_numerator: u64,
+    ) -> Result<(), GovernorError>;
+
+    fn _quorum_numerator_at(&self, block_number: BlockNumber) -> u64;
+
+    fn _quorum_denominator(&self) -> u64;
+
+    /// Returns the total supply of votes at a specific block number. By default it is read from
+    /// the voting module, can be overridden to read it from a different Votes source (e.g.
+    /// `Votes::get_past_total_supply` when the token and the governor are the same contract).
+    fn _get_past_total_supply(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<u64, GovernorError>;
+
+    /// Overridden version of the Governor quorum, where the quorum for a block number is
+    /// `supply * numerator / denominator`.
+    ///
+    /// Note: The user must override the `_quorum` method of governor::Internal to call this
+    /// method.
+    fn _quorum_fraction(&self, block_number: BlockNumber) -> Result<u64, GovernorError>;
+}
+
+impl<T, C, V> Internal for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>> + Storage<Data>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>
+        + OccupiedStorage<STORAGE_KEY, WithData = Data>,
+{
+    default fn _emit_quorum_numerator_updated(
+        &self,
+        _old_quorum_numerator: u64,
+        _new_quorum_numerator: u64,
+    ) {
+    }
+
+    default fn _init_with_quorum_numerator(
+        &mut self,
+        quorum_numerator: u64,
+    ) -> Result<(), GovernorError> {
+        self._update_quorum_numerator(quorum_numerator)
+    }
+
+    default fn _update_quorum_numerator(
+        &mut self,
+        new_quorum_numerator: u64,
+    ) -> Result<(), GovernorError> {
+        if new_quorum_numerator > self._quorum_denominator() {
+            return Err(GovernorError::InvalidQuorumFraction)
+        }
+
+        let old_quorum_numerator = self.quorum_numerator();
+        let block_number = Self::env().block_number();
+        let history = &mut self.data::<Data>().quorum_numerator_history;
+
+        // Only one checkpoint is kept per block.
+        match history.last_mut() {
+            Some(checkpoint) if checkpoint.from_block == block_number => {
+                checkpoint.votes = new_quorum_numerator
+            }
+            _ => {
+                history.push(Checkpoint {
+                    from_block: block_number,
+                    votes: new_quorum_numerator,
+                })
+            }
+        }
+
+        self._emit_quorum_numerator_updated(old_quorum_numerator, new_quorum_numerator);
+
+        Ok(())
+    }
+
+    default fn _quorum_numerator_at(&self, block_number: BlockNumber) -> u64 {
+        let history = &self.data::<Data>().quorum_numerator_history;
+
+        match history
+            .partition_point(|checkpoint| checkpoint.from_block <= block_number)
+            .checked_sub(1)
+        {
+            Some(index) => history[index].votes,
+            None => 0,
+        }
+    }
+
+    default fn _quorum_denominator(&self) -> u64 {
+        100
+    }
+
+    default fn _get_past_total_supply(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<u64, GovernorError> {
+        self.data::<governor::Data<C, V>>()
+            .voting_module
+            ._get_past_total_supply(block_number)
+            .ok_or(GovernorError::Custom(String::from(
+                "No total supply source",
+            )))
+    }
+
+    default fn _quorum_fraction(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<u64, GovernorError> {
+        let total_supply = self._get_past_total_supply(block_number)? as u128;
+        let numerator = self._quorum_numerator_at(block_number) as u128;
+        let denominator = self._quorum_denominator() as u128;
+
+        Ok((total_supply * numerator / denominator) as u64)
+    }
+}
@@ -1,3 +1,21 @@
+/// Governor commit reveal extension
+#[cfg(feature = "governor_commit_reveal")]
+pub mod governor_commit_reveal;
+/// Governor prevent late quorum extension
+#[cfg(feature = "governor_prevent_late_quorum")]
+pub mod governor_prevent_late_quorum;
+/// Governor proposal deposit extension
+#[cfg(feature = "governor_proposal_deposit")]
+pub mod governor_proposal_deposit;
 /// Governor settings extension
 #[cfg(feature = "governor_settings")]
 pub mod governor_settings;
+/// Governor storage extension
+#[cfg(feature = "governor_storage")]
+pub mod governor_storage;
+/// Governor timelock control extension
+#[cfg(feature = "governor_timelock_control")]
+pub mod governor_timelock_control;
+/// Governor votes quorum fraction extension
+#[cfg(feature = "governor_votes_quorum_fraction")]
+pub mod governor_votes_quorum_fraction;
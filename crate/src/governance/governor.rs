@@ -4,7 +4,10 @@ pub use crate::{
     traits::governance::*,
 };
 
-use crate::traits::errors::CountingError;
+use crate::traits::{
+    errors::CountingError,
+    signature,
+};
 
 use ink::{
     env::{
@@ -21,15 +24,19 @@ use ink::{
         collections::vec_deque::VecDeque,
         vec::Vec,
     },
-    storage::traits::{
-        AutoStorableHint,
-        ManualKey,
-        Storable,
-        StorableHint,
+    storage::{
+        traits::{
+            AutoStorableHint,
+            ManualKey,
+            Storable,
+            StorableHint,
+        },
+        Lazy,
     },
 };
 use openbrush::{
     modifier_definition,
+    modifiers,
     storage::Mapping,
     traits::{
         AccountId,
@@ -75,6 +82,7 @@ impl Counter for Counting {
     default fn _quorum_reached(
         &self,
         _proposal_id: &ProposalId,
+        _quorum: Option<u64>,
     ) -> Result<bool, CountingError> {
         Err(CountingError::Custom(String::from("No module")))
     }
@@ -142,6 +150,12 @@ where
     pub counting_module: C,
     /// The sub-module that determine the source of voting power.
     pub voting_module: V,
+    /// The account that can cancel any proposal before its execution.
+    pub guardian: Lazy<Option<AccountId>>,
+    /// Map every account to the nonce of its next signed ballot
+    pub nonces: Mapping<AccountId, u64>,
+    /// Map every ProposalId to the account that created the proposal
+    pub proposers: Mapping<ProposalId, AccountId>,
     pub _reserved: Option<()>,
 }
 
@@ -194,35 +208,7 @@ where
         &self,
         proposal_id: ProposalId,
     ) -> Result<ProposalState, GovernorError> {
-        let proposal = self
-            .data()
-            .proposals
-            .get(&proposal_id)
-            .ok_or(GovernorError::ProposalNotFound)?;
-        if proposal.executed {
-            return Ok(ProposalState::Executed)
-        }
-        if proposal.canceled {
-            return Ok(ProposalState::Canceled)
-        }
-
-        let snapshot = self.proposal_snapshot(proposal_id)?;
-
-        if snapshot > Self::env().block_number() {
-            return Ok(ProposalState::Pending)
-        }
-
-        let deadline = self.proposal_deadline(proposal_id)?;
-
-        if deadline >= Self::env().block_number() {
-            return Ok(ProposalState::Active)
-        }
-
-        if self._quorum_reached(&proposal_id) && self._vote_succeeded(&proposal_id) {
-            Ok(ProposalState::Succeeded)
-        } else {
-            Ok(ProposalState::Defeated)
-        }
+        self._state(&proposal_id)
     }
 
     default fn proposal_snapshot(
@@ -242,17 +228,11 @@ where
         &self,
         proposal_id: ProposalId,
     ) -> Result<BlockNumber, GovernorError> {
-        let vote_end = self
-            .data()
-            .proposals
-            .get(&proposal_id)
-            .ok_or(GovernorError::ProposalNotFound)?
-            .vote_end;
-        Ok(vote_end)
+        self._proposal_deadline(&proposal_id)
     }
 
     default fn counting_mode(&self) -> String {
-        String::from("")
+        self.data().counting_module._counting_mode()
     }
 
     default fn voting_delay(&self) -> u32 {
@@ -283,7 +263,7 @@ where
                 .unwrap();
         let proposal_id = self._hash_proposal(&proposal, &description_hash);
 
-        if proposal.selector.is_empty() && description.is_empty() {
+        if proposal.transactions.is_empty() && description.is_empty() {
             return Err(GovernorError::EmptyProposal)
         }
 
@@ -302,6 +282,22 @@ where
         };
 
         self.data().proposals.insert(&proposal_id, &proposal_core);
+        self.data()
+            .proposers
+            .insert(&proposal_id, &Self::env().caller());
+
+        self.data().counting_module._proposal_created(
+            &Self::env().caller(),
+            &proposal_id,
+            &proposal,
+        )?;
+
+        self._after_propose(
+            Self::env().caller(),
+            &proposal_id,
+            &proposal,
+            &description_hash,
+        )?;
 
         self._emit_proposal_created(
             Self::env().caller(),
@@ -346,6 +342,44 @@ where
         Ok(proposal_id)
     }
 
+    default fn cancel(
+        &mut self,
+        proposal: Proposal,
+        description_hash: Hash,
+    ) -> Result<ProposalId, GovernorError> {
+        let proposal_id = self._hash_proposal(&proposal, &description_hash);
+        let caller = Self::env().caller();
+
+        if self.guardian() != Some(caller) {
+            let proposer = self
+                .data()
+                .proposers
+                .get(&proposal_id)
+                .ok_or(GovernorError::ProposalNotFound)?;
+            if caller != proposer {
+                return Err(GovernorError::OnlyProposerOrGuardian)
+            }
+            if self.state(proposal_id)? != ProposalState::Pending {
+                return Err(GovernorError::ProposalNotPending)
+            }
+        }
+
+        self._cancel(&proposal, &description_hash)
+    }
+
+    default fn guardian(&self) -> Option<AccountId> {
+        self.data().guardian.get().flatten()
+    }
+
+    #[modifiers(only_governance())]
+    default fn set_guardian(
+        &mut self,
+        new_guardian: Option<AccountId>,
+    ) -> Result<(), GovernorError> {
+        self._set_guardian(new_guardian);
+        Ok(())
+    }
+
     default fn get_votes(
         &self,
         account: AccountId,
@@ -401,9 +435,51 @@ where
         Ok(votes)
     }
 
-    default fn relay(&mut self, _proposal: Proposal) -> Result<(), GovernorError> {
-        // TODO:
-        unimplemented!()
+    default fn nonces(&self, account: AccountId) -> u64 {
+        self.data().nonces.get(&account).unwrap_or_default()
+    }
+
+    default fn cast_vote_by_sig(
+        &mut self,
+        proposal_id: ProposalId,
+        support: u8,
+        voter: AccountId,
+        signature: Signature,
+    ) -> Result<u64, GovernorError> {
+        let votes = self._cast_vote_by_sig(
+            &proposal_id,
+            &voter,
+            support,
+            &String::from(""),
+            &self._default_params(),
+            &signature,
+        )?;
+        Ok(votes)
+    }
+
+    default fn cast_vote_with_reason_and_params_by_sig(
+        &mut self,
+        proposal_id: ProposalId,
+        support: u8,
+        voter: AccountId,
+        reason: String,
+        params: Vec<u8>,
+        signature: Signature,
+    ) -> Result<u64, GovernorError> {
+        let votes = self._cast_vote_by_sig(
+            &proposal_id,
+            &voter,
+            support,
+            &reason,
+            &params,
+            &signature,
+        )?;
+        Ok(votes)
+    }
+
+    #[modifiers(only_governance())]
+    default fn relay(&mut self, transaction: Transaction) -> Result<(), GovernorError> {
+        self._call(&transaction)
     }
 }
 
@@ -421,6 +497,11 @@ pub trait Internal {
     );
     fn _emit_proposal_canceled(&self, _proposal_id: ProposalId);
     fn _emit_proposal_executed(&self, _proposal_id: ProposalId);
+    fn _emit_guardian_set(
+        &self,
+        _old_guardian: Option<AccountId>,
+        _new_guardian: Option<AccountId>,
+    );
     fn _emit_vote_cast(
         &self,
         _voter: AccountId,
@@ -438,6 +519,37 @@ pub trait Internal {
         _reason: String,
         _params: Vec<u8>,
     );
+    fn _emit_vote_changed(
+        &self,
+        _voter: AccountId,
+        _proposal_id: ProposalId,
+        _old_support: u8,
+        _old_weight: u64,
+        _new_support: u8,
+        _new_weight: u64,
+    );
+    fn _emit_vote_retracted(
+        &self,
+        _voter: AccountId,
+        _proposal_id: ProposalId,
+        _support: u8,
+        _weight: u64,
+    );
+
+    /// Returns the state of a proposal as tracked by the core module.
+    ///
+    /// Note: Extensions that refine the state of a proposal (e.g. a time-lock) build on top of this
+    /// method, so it must not be overridden to call them.
+    fn _state(&self, proposal_id: &ProposalId) -> Result<ProposalState, GovernorError>;
+
+    /// Returns the deadline of a proposal as tracked by the core module.
+    ///
+    /// Note: Extensions that refine the deadline of a proposal (e.g. a late quorum protection)
+    /// build on top of this method, so it must not be overridden to call them.
+    fn _proposal_deadline(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<BlockNumber, GovernorError>;
 
     /// Returns the number of votes required in order for a voter to become a proposer.
     fn _proposal_threshold(&self) -> u64;
@@ -455,12 +567,22 @@ pub trait Internal {
 
     fn _hash_proposal(&self, proposal: &Proposal, description_hash: &Hash) -> ProposalId;
 
-    /// If amount of votes already cast passes the threshold limit.
+    /// Minimum number of cast voted required for a proposal whose snapshot is at a specific
+    /// blockNumber. By default the quorum is provided by the voting module, can be overridden to
+    /// provide a different quorum source.
+    fn _quorum(&self, block_number: BlockNumber) -> Result<u64, GovernorError>;
+
+    /// If amount of votes already cast passes the quorum at the proposal snapshot.
+    ///
+    /// Note: The counting module decides if a proposal whose quorum is not provided reaches it.
     fn _quorum_reached(&self, proposal_id: &ProposalId) -> bool;
 
     /// If the proposal is successful or not.
     fn _vote_succeeded(&self, proposal_id: &ProposalId) -> bool;
 
+    /// If the proposal is vetoed or not.
+    fn _vote_vetoed(&self, proposal_id: &ProposalId) -> bool;
+
     /// Get the voting weight of account at a specific blockNumber, for a vote as described by params.
     fn _get_votes(
         &self,
@@ -479,7 +601,13 @@ pub trait Internal {
         support: u8,
         weight: u64,
         params: &[u8],
-    );
+    ) -> Result<(), GovernorError>;
+
+    /// Hook after a vote is counted.
+    fn _after_count_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+    ) -> Result<(), GovernorError>;
 
     /// Default additional encoded parameters used by castVote methods that don’t include them
     ///
@@ -487,20 +615,56 @@ pub trait Internal {
     /// meaning of the additional params, in the context of that implementation
     fn _default_params(&self) -> Vec<u8>;
 
-    /// Internal execution mechanism. Can be overridden to implement different execution
-    /// mechanism
+    /// Internal execution mechanism: execute in order all the transactions of the proposal. Can be
+    /// overridden to implement different execution mechanism
+    ///
+    /// Note: The execution stops at the first transaction that fails, the returned error reverts
+    /// the transactions already executed.
     fn _execute(
         &mut self,
         proposal_id: &ProposalId,
         proposal: &Proposal,
     ) -> Result<(), GovernorError>;
 
+    /// Perform a single call to an arbitrary target, its output is checked by
+    /// `_check_call_output`.
+    fn _call(&mut self, transaction: &Transaction) -> Result<(), GovernorError>;
+
+    /// Check the output of a call performed by the governor.
+    ///
+    /// Note: The output of a called message that returns `Err` is decoded as a successful output,
+    /// so it can be checked only with the real return type of the message. By default the output
+    /// must be `()` or a `Result` (see `CallOutput::check_result`), it should be overridden to
+    /// call messages with other return types (e.g. with `CallOutput::decode_result`).
+    fn _check_call_output(
+        &self,
+        transaction: &Transaction,
+        output: &CallOutput,
+    ) -> Result<(), GovernorError>;
+
     /// Hook before execution is triggered.
+    ///
+    /// Note: When the executor is not the governor (e.g. a timelock), the selectors of the
+    /// transactions whose callee is the governor are queued so that the `only_governance` modifier
+    /// accepts them. The transactions to other contracts are not queued, since they never reach
+    /// the modifier of the governor.
     fn _before_execute(&mut self, proposal: &Proposal) -> Result<(), GovernorError>;
 
     /// Hook after execution is triggered.
     fn _after_execute(&mut self) -> Result<(), GovernorError>;
 
+    /// Hook after a proposal is created.
+    fn _after_propose(
+        &mut self,
+        proposer: AccountId,
+        proposal_id: &ProposalId,
+        proposal: &Proposal,
+        description_hash: &Hash,
+    ) -> Result<(), GovernorError>;
+
+    /// Hook after cancel is triggered.
+    fn _after_cancel(&mut self, proposal_id: &ProposalId) -> Result<(), GovernorError>;
+
     /// Internal cancel mechanism: locks up the proposal timer, preventing it from being re-submitted. Marks it as canceled to allow distinguishing it from executed proposals.
     ///
     /// Emits a ProposalCanceled event.
@@ -534,6 +698,33 @@ pub trait Internal {
         params: &[u8],
     ) -> Result<u64, GovernorError>;
 
+    /// Returns the message that the voter signs off-chain to cast a vote by signature: the
+    /// blake2x256 hash of the SCALE encoded tuple (governor `AccountId`, `Ballot`).
+    fn _hash_ballot(&self, ballot: &Ballot) -> [u8; 32];
+
+    /// Consume the current nonce of an account and return it.
+    fn _use_nonce(&mut self, account: &AccountId) -> u64;
+
+    /// Internal vote casting by signature mechanism: check that the signature of the ballot,
+    /// built with the current nonce of the voter, was produced by the voter and cast the vote
+    /// through `_cast_vote_with_params`.
+    ///
+    /// Emits a VoteCast event or VoteCastWithParams event depending on the length of params.
+    fn _cast_vote_by_sig(
+        &mut self,
+        proposal_id: &ProposalId,
+        voter: &AccountId,
+        support: u8,
+        reason: &String,
+        params: &[u8],
+        signature: &Signature,
+    ) -> Result<u64, GovernorError>;
+
+    /// Set the account that can cancel any proposal before its execution.
+    ///
+    /// Emits a GuardianSet event.
+    fn _set_guardian(&mut self, new_guardian: Option<AccountId>);
+
     /// Address through which the governor executes action. Will be overloaded by module that execute actions through another contract such as a time-lock.
     fn _executor(&self) -> AccountId;
 }
@@ -563,6 +754,12 @@ where
     }
     default fn _emit_proposal_canceled(&self, _proposal_id: ProposalId) {}
     default fn _emit_proposal_executed(&self, _proposal_id: ProposalId) {}
+    default fn _emit_guardian_set(
+        &self,
+        _old_guardian: Option<AccountId>,
+        _new_guardian: Option<AccountId>,
+    ) {
+    }
     default fn _emit_vote_cast(
         &self,
         _voter: AccountId,
@@ -582,6 +779,72 @@ where
         _params: Vec<u8>,
     ) {
     }
+    default fn _emit_vote_changed(
+        &self,
+        _voter: AccountId,
+        _proposal_id: ProposalId,
+        _old_support: u8,
+        _old_weight: u64,
+        _new_support: u8,
+        _new_weight: u64,
+    ) {
+    }
+    default fn _emit_vote_retracted(
+        &self,
+        _voter: AccountId,
+        _proposal_id: ProposalId,
+        _support: u8,
+        _weight: u64,
+    ) {
+    }
+
+    default fn _state(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalState, GovernorError> {
+        let proposal = self
+            .data()
+            .proposals
+            .get(proposal_id)
+            .ok_or(GovernorError::ProposalNotFound)?;
+        if proposal.executed {
+            return Ok(ProposalState::Executed)
+        }
+        if proposal.canceled {
+            return Ok(ProposalState::Canceled)
+        }
+
+        let snapshot = self.proposal_snapshot(*proposal_id)?;
+
+        if snapshot > Self::env().block_number() {
+            return Ok(ProposalState::Pending)
+        }
+
+        let deadline = self.proposal_deadline(*proposal_id)?;
+
+        if deadline >= Self::env().block_number() {
+            return Ok(ProposalState::Active)
+        }
+
+        if self._quorum_reached(proposal_id) && self._vote_succeeded(proposal_id) {
+            Ok(ProposalState::Succeeded)
+        } else {
+            Ok(ProposalState::Defeated)
+        }
+    }
+
+    default fn _proposal_deadline(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<BlockNumber, GovernorError> {
+        let vote_end = self
+            .data()
+            .proposals
+            .get(proposal_id)
+            .ok_or(GovernorError::ProposalNotFound)?
+            .vote_end;
+        Ok(vote_end)
+    }
 
     default fn _proposal_threshold(&self) -> u64 {
         0
@@ -608,10 +871,24 @@ where
         Hash::try_from(Self::env().hash_bytes::<Blake2x256>(&hash_data).as_ref()).unwrap()
     }
 
+    default fn _quorum(&self, block_number: BlockNumber) -> Result<u64, GovernorError> {
+        self.data()
+            .voting_module
+            ._quorum(block_number)
+            .ok_or(GovernorError::Custom(String::from("No quorum source")))
+    }
+
     default fn _quorum_reached(&self, proposal_id: &ProposalId) -> bool {
+        // The counting module decides whether a proposal without a quorum source reaches the
+        // quorum
+        let quorum = self
+            .proposal_snapshot(*proposal_id)
+            .and_then(|snapshot| self._quorum(snapshot))
+            .ok();
+
         self.data()
             .counting_module
-            ._quorum_reached(proposal_id)
+            ._quorum_reached(proposal_id, quorum)
             .unwrap()
     }
 
@@ -622,6 +899,13 @@ where
             .unwrap()
     }
 
+    default fn _vote_vetoed(&self, proposal_id: &ProposalId) -> bool {
+        self.data()
+            .counting_module
+            ._vote_vetoed(proposal_id)
+            .unwrap()
+    }
+
     default fn _get_votes(
         &self,
         account: &AccountId,
@@ -646,11 +930,21 @@ where
         support: u8,
         weight: u64,
         params: &[u8],
-    ) {
-        self.data()
-            .counting_module
-            ._count_vote(proposal_id, account, support, weight, params)
-            .unwrap()
+    ) -> Result<(), GovernorError> {
+        Ok(self.data().counting_module._count_vote(
+            proposal_id,
+            account,
+            support,
+            weight,
+            params,
+        )?)
+    }
+
+    default fn _after_count_vote(
+        &mut self,
+        _proposal_id: &ProposalId,
+    ) -> Result<(), GovernorError> {
+        Ok(())
     }
 
     default fn _default_params(&self) -> Vec<u8> {
@@ -662,33 +956,48 @@ where
         proposal_id: &ProposalId,
         proposal: &Proposal,
     ) -> Result<(), GovernorError> {
+        for transaction in proposal.transactions.iter() {
+            self._call(transaction)?;
+        }
+
+        self._emit_proposal_executed(*proposal_id);
+
+        Ok(())
+    }
+
+    default fn _call(&mut self, transaction: &Transaction) -> Result<(), GovernorError> {
         // Flush the state into storage before the cross call.
         // Because during cross call we can call this contract.
         self.flush();
         let result = build_call::<DefaultEnvironment>()
             .call_type(
-                Call::new(proposal.callee)
+                Call::new(transaction.callee)
                     .gas_limit(0)
-                    .transferred_value(proposal.transferred_value),
+                    .transferred_value(transaction.transferred_value),
             )
             .exec_input(
-                ExecutionInput::new(proposal.selector.into())
-                    .push_arg(CallInput(&proposal.input)),
+                ExecutionInput::new(transaction.selector.into())
+                    .push_arg(CallInput(&transaction.input)),
             )
-            .returns::<()>()
+            .returns::<CallOutput>()
             .call_flags(CallFlags::default().set_allow_reentry(true))
             .try_invoke()
             .map_err(|_| GovernorError::CallRevertedWithoutMessage);
 
         // Load the state of the contract after the cross call.
         self.load();
-        self._emit_proposal_executed(*proposal_id);
 
-        let post_call = result?;
+        let output = result??;
 
-        post_call?;
+        self._check_call_output(transaction, &output)
+    }
 
-        Ok(())
+    default fn _check_call_output(
+        &self,
+        _transaction: &Transaction,
+        output: &CallOutput,
+    ) -> Result<(), GovernorError> {
+        output.check_result()
     }
 
     default fn _before_execute(
@@ -696,7 +1005,11 @@ where
         proposal: &Proposal,
     ) -> Result<(), GovernorError> {
         if self._executor() != Self::env().account_id() {
-            self.data().governance_call.push_back(proposal.selector);
+            for transaction in proposal.transactions.iter() {
+                if transaction.callee == Self::env().account_id() {
+                    self.data().governance_call.push_back(transaction.selector);
+                }
+            }
         }
         Ok(())
     }
@@ -734,10 +1047,29 @@ where
             }
         }
 
+        self._after_cancel(&proposal_id)?;
+
         self._emit_proposal_canceled(proposal_id);
         Ok(proposal_id)
     }
 
+    default fn _after_propose(
+        &mut self,
+        _proposer: AccountId,
+        _proposal_id: &ProposalId,
+        _proposal: &Proposal,
+        _description_hash: &Hash,
+    ) -> Result<(), GovernorError> {
+        Ok(())
+    }
+
+    default fn _after_cancel(
+        &mut self,
+        _proposal_id: &ProposalId,
+    ) -> Result<(), GovernorError> {
+        Ok(())
+    }
+
     default fn _cast_vote(
         &mut self,
         proposal_id: &ProposalId,
@@ -776,7 +1108,23 @@ where
 
         let weight = self._get_votes(account, proposal_core.vote_start, params)?;
 
-        self._count_vote(proposal_id, account, support, weight, params);
+        let old_vote = self.data().counting_module._vote_of(proposal_id, account);
+
+        self._count_vote(proposal_id, account, support, weight, params)?;
+
+        self._after_count_vote(proposal_id)?;
+
+        // The counting module accepted a new vote of the account, so it replaced the old one
+        if let Some((old_support, old_weight)) = old_vote {
+            self._emit_vote_changed(
+                *account,
+                *proposal_id,
+                old_support,
+                old_weight,
+                support,
+                weight,
+            );
+        }
 
         if params.is_empty() {
             self._emit_vote_cast(
@@ -800,18 +1148,50 @@ where
         Ok(weight)
     }
 
-    default fn _executor(&self) -> AccountId {
-        Self::env().account_id()
+    default fn _hash_ballot(&self, ballot: &Ballot) -> [u8; 32] {
+        signature::hash_payload(&(Self::env().account_id(), ballot))
     }
-}
 
-/// A wrapper that allows us to encode a blob of bytes.
-///
-/// We use this to pass the set of untyped (bytes) parameters to the `CallBuilder`.
-pub struct CallInput<'a>(&'a [u8]);
+    default fn _use_nonce(&mut self, account: &AccountId) -> u64 {
+        let nonce = self.data().nonces.get(account).unwrap_or_default();
+        self.data().nonces.insert(account, &(nonce + 1));
+        nonce
+    }
+
+    default fn _cast_vote_by_sig(
+        &mut self,
+        proposal_id: &ProposalId,
+        voter: &AccountId,
+        support: u8,
+        reason: &String,
+        params: &[u8],
+        signature: &Signature,
+    ) -> Result<u64, GovernorError> {
+        let ballot = Ballot {
+            proposal_id: *proposal_id,
+            support,
+            reason: reason.clone(),
+            params: params.to_vec(),
+            nonce: self.nonces(*voter),
+        };
+
+        if !signature::verify(voter, &self._hash_ballot(&ballot), signature) {
+            return Err(GovernorError::InvalidSignature)
+        }
 
-impl<'a> scale::Encode for CallInput<'a> {
-    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
-        dest.write(self.0);
+        self._use_nonce(voter);
+
+        self._cast_vote_with_params(proposal_id, voter, support, reason, params)
+    }
+
+    default fn _set_guardian(&mut self, new_guardian: Option<AccountId>) {
+        let old_guardian = self.guardian();
+        self._emit_guardian_set(old_guardian, new_guardian);
+
+        self.data().guardian.set(&new_guardian);
+    }
+
+    default fn _executor(&self) -> AccountId {
+        Self::env().account_id()
     }
 }
@@ -4,3 +4,4 @@ pub mod governor;
 
 pub mod extensions;
 pub mod modules;
+pub mod utils;
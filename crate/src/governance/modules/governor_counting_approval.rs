@@ -0,0 +1,305 @@
+pub use crate::{
+    governance::modules::{
+        governor_counting_approval,
+        governor_counting_approval::Internal as _,
+    },
+    traits::{
+        errors::{
+            CountingError,
+            CountingSimpleError,
+        },
+        governance::modules::counting_approval::*,
+    },
+};
+
+use crate::{
+    governance::governor::*,
+    governor::modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+use ink::{
+    prelude::vec::Vec,
+    storage::traits::{
+        AutoStorableHint,
+        ManualKey,
+        Storable,
+        StorableHint,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        BlockNumber,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Counting);
+
+/// Counting storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Counting {
+    /// Map every proposal to its options
+    pub proposal_options: Mapping<ProposalId, Vec<String>>,
+    /// Map every proposal to the number of options that win it
+    pub number_of_winners: Mapping<ProposalId, u8>,
+    pub proposal_votes: Mapping<ProposalId, ApprovalVotes>,
+    pub has_voted: Mapping<(AccountId, ProposalId), bool>,
+    pub _reserved: Option<()>,
+}
+
+impl Counter for Counting {
+    default fn _counting_mode(&self) -> String {
+        String::from(
+            "support=approval&quorum=participation&params=approvals&options=proposal_options",
+        )
+    }
+
+    default fn _quorum_reached(
+        &self,
+        proposal_id: &ProposalId,
+        quorum: Option<u64>,
+    ) -> Result<bool, CountingError> {
+        let total_votes = self
+            .proposal_votes
+            .get(proposal_id)
+            .unwrap_or_default()
+            .total_votes;
+        Ok(quorum.map_or(false, |quorum| quorum <= total_votes))
+    }
+
+    default fn _vote_succeeded(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, CountingError> {
+        Ok(!self._winning_options(proposal_id).is_empty())
+    }
+
+    default fn _count_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+        support: u8,
+        weight: u64,
+        params: &[u8],
+    ) -> Result<(), CountingError> {
+        if self._has_voted(*account, *proposal_id) {
+            return Err(CountingError::VoteAlreadyCast)
+        }
+
+        let mut proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        let options = proposal_votes.option_votes.len();
+
+        let approvals: Vec<usize> = if params.is_empty() {
+            Vec::from([usize::from(support)])
+        } else {
+            if params.len() != (options + 7) / 8 {
+                return Err(CountingError::InvalidParams)
+            }
+            (0..params.len() * 8)
+                .filter(|option| params[option / 8] & (1 << (option % 8)) != 0)
+                .collect()
+        };
+
+        if approvals.is_empty() {
+            return Err(CountingError::InvalidParams)
+        }
+        for option in approvals {
+            let votes = proposal_votes
+                .option_votes
+                .get_mut(option)
+                .ok_or(CountingError::InvalidVoteType)?;
+            *votes = votes
+                .checked_add(weight)
+                .ok_or(CountingError::VotesOverflow)?;
+        }
+        proposal_votes.total_votes = proposal_votes
+            .total_votes
+            .checked_add(weight)
+            .ok_or(CountingError::VotesOverflow)?;
+
+        self.has_voted.insert(&(*account, *proposal_id), &true);
+        self.proposal_votes.insert(proposal_id, &proposal_votes);
+
+        Ok(())
+    }
+}
+
+impl<T, C, V> CountingApproval for T
+where
+    C: Counter + Internal,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>,
+{
+    default fn propose_with_options(
+        &mut self,
+        proposal: Proposal,
+        description: String,
+        options: Vec<String>,
+        winners: u8,
+    ) -> Result<ProposalId, GovernorError> {
+        if options.len() < 2
+            || options.len() > usize::from(u8::MAX) + 1
+            || winners == 0
+            || usize::from(winners) > options.len()
+        {
+            return Err(GovernorError::InvalidProposalOptions)
+        }
+
+        let proposal_id = self.propose(proposal, description)?;
+
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._set_proposal_options(&proposal_id, options, winners);
+
+        Ok(proposal_id)
+    }
+
+    default fn quorum(&self, block_number: BlockNumber) -> Result<u64, GovernorError> {
+        governor::Internal::_quorum(self, block_number)
+    }
+
+    default fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._has_voted(account, proposal_id)
+    }
+
+    default fn proposal_options(&self, proposal_id: ProposalId) -> Vec<String> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            .proposal_options
+            .get(&proposal_id)
+            .unwrap_or_default()
+    }
+
+    default fn number_of_winners(&self, proposal_id: ProposalId) -> u8 {
+        self.data::<Data<C, V>>()
+            .counting_module
+            .number_of_winners
+            .get(&proposal_id)
+            .unwrap_or_default()
+    }
+
+    default fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<ApprovalVotes, CountingSimpleError> {
+        let proposal_votes = self
+            .data::<Data<C, V>>()
+            .counting_module
+            ._proposal_votes(&proposal_id)?;
+        Ok(proposal_votes)
+    }
+
+    default fn winning_options(&self, proposal_id: ProposalId) -> Vec<u8> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._winning_options(&proposal_id)
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool;
+
+    /// Store the options of a newly created proposal and the number of options that win it,
+    /// every option starts with zero votes.
+    fn _set_proposal_options(
+        &mut self,
+        proposal_id: &ProposalId,
+        options: Vec<String>,
+        winners: u8,
+    );
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ApprovalVotes, CountingSimpleError>;
+
+    /// Returns the options with the most votes, up to the number of winners of the proposal. An
+    /// option without votes never wins.
+    fn _winning_options(&self, proposal_id: &ProposalId) -> Vec<u8>;
+}
+
+impl Internal for Counting {
+    fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool {
+        self.has_voted
+            .get(&(account, proposal_id))
+            .unwrap_or_default()
+    }
+
+    fn _set_proposal_options(
+        &mut self,
+        proposal_id: &ProposalId,
+        options: Vec<String>,
+        winners: u8,
+    ) {
+        let proposal_votes = ApprovalVotes {
+            option_votes: options.iter().map(|_| 0).collect(),
+            total_votes: 0,
+        };
+
+        self.proposal_votes.insert(proposal_id, &proposal_votes);
+        self.proposal_options.insert(proposal_id, &options);
+        self.number_of_winners.insert(proposal_id, &winners);
+    }
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ApprovalVotes, CountingSimpleError> {
+        self.proposal_votes
+            .get(proposal_id)
+            .ok_or(CountingSimpleError::NoProposal)
+    }
+
+    fn _winning_options(&self, proposal_id: &ProposalId) -> Vec<u8> {
+        let winners =
+            usize::from(self.number_of_winners.get(proposal_id).unwrap_or_default());
+        let mut ranking: Vec<(u8, u64)> = self
+            .proposal_votes
+            .get(proposal_id)
+            .unwrap_or_default()
+            .option_votes
+            .iter()
+            .enumerate()
+            .filter(|(_, votes)| **votes > 0)
+            .map(|(option, votes)| (option as u8, *votes))
+            .collect();
+        ranking.sort_by(|a, b| b.1.cmp(&a.1));
+
+        // The last winning option is tied with an option that does not win
+        if winners == 0
+            || (ranking.len() > winners && ranking[winners].1 == ranking[winners - 1].1)
+        {
+            return Vec::new()
+        }
+
+        ranking
+            .into_iter()
+            .take(winners)
+            .map(|(option, _)| option)
+            .collect()
+    }
+}
@@ -0,0 +1,298 @@
+pub use crate::{
+    governance::modules::{
+        governor_counting_conviction,
+        governor_counting_conviction::Internal as _,
+    },
+    traits::{
+        errors::{
+            CountingError,
+            CountingSimpleError,
+        },
+        governance::modules::counting_conviction::*,
+    },
+};
+
+use crate::{
+    governance::governor::*,
+    governor::modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+    traits::token::psp22::extensions::votes::PSP22VotesRef,
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        BlockNumber,
+        OccupiedStorage,
+        Storage,
+        String,
+        ZERO_ADDRESS,
+    },
+};
+
+use ink::storage::traits::{
+    AutoStorableHint,
+    ManualKey,
+    Storable,
+    StorableHint,
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Counting);
+
+/// Counting storage struct
+#[derive(Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Counting {
+    /// Map every proposal to its votes multiplied by the conviction
+    pub proposal_votes: Mapping<ProposalId, ProposalVote>,
+    /// Map every proposal to its for and abstain votes without conviction
+    pub proposal_turnout: Mapping<ProposalId, u64>,
+    pub voter_convictions: Mapping<(AccountId, ProposalId), Conviction>,
+    /// The token contract that locks the voting weight
+    pub token: AccountId,
+    /// The number of blocks of a lock period
+    pub lock_period: BlockNumber,
+    pub _reserved: Option<()>,
+}
+
+impl Default for Counting {
+    fn default() -> Self {
+        Counting {
+            proposal_votes: Default::default(),
+            proposal_turnout: Default::default(),
+            voter_convictions: Default::default(),
+            token: ZERO_ADDRESS.into(),
+            lock_period: Default::default(),
+            _reserved: Default::default(),
+        }
+    }
+}
+
+impl Counter for Counting {
+    default fn _counting_mode(&self) -> String {
+        String::from("support=bravo&quorum=for,abstain&params=conviction")
+    }
+
+    default fn _quorum_reached(
+        &self,
+        proposal_id: &ProposalId,
+        quorum: Option<u64>,
+    ) -> Result<bool, CountingError> {
+        Ok(quorum.map_or(false, |quorum| {
+            quorum <= self.proposal_turnout.get(proposal_id).unwrap_or_default()
+        }))
+    }
+
+    default fn _vote_succeeded(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, CountingError> {
+        let proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        Ok(proposal_votes.for_votes > proposal_votes.against_votes)
+    }
+
+    default fn _count_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+        support: u8,
+        weight: u64,
+        params: &[u8],
+    ) -> Result<(), CountingError> {
+        if self._voter_conviction(*account, *proposal_id).is_some() {
+            return Err(CountingError::VoteAlreadyCast)
+        }
+
+        let conviction = match params {
+            [] => Conviction::None,
+            [conviction] => (*conviction).try_into()?,
+            _ => return Err(CountingError::InvalidParams),
+        };
+        let votes = conviction
+            .votes(weight)
+            .ok_or(CountingError::VotesOverflow)?;
+
+        let mut proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        let mut turnout = self.proposal_turnout.get(proposal_id).unwrap_or_default();
+        let proposal_votes_type = match support.try_into()? {
+            VoteType::Against => &mut proposal_votes.against_votes,
+            VoteType::For => {
+                turnout = turnout.saturating_add(weight);
+                &mut proposal_votes.for_votes
+            }
+            VoteType::Abstain => {
+                turnout = turnout.saturating_add(weight);
+                &mut proposal_votes.abstain_votes
+            }
+        };
+        *proposal_votes_type = proposal_votes_type
+            .checked_add(votes)
+            .ok_or(CountingError::VotesOverflow)?;
+
+        self._lock_weight(account, weight, conviction)?;
+
+        self.voter_convictions
+            .insert(&(*account, *proposal_id), &conviction);
+        self.proposal_votes.insert(proposal_id, &proposal_votes);
+        self.proposal_turnout.insert(proposal_id, &turnout);
+
+        Ok(())
+    }
+}
+
+impl<T, C, V> CountingConviction for T
+where
+    C: Counter + Internal,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>,
+{
+    default fn quorum(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<u64, CountingSimpleError> {
+        Ok(governor::Internal::_quorum(self, block_number)?)
+    }
+
+    default fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._voter_conviction(account, proposal_id)
+            .is_some()
+    }
+
+    default fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError> {
+        let proposal_vote = self
+            .data::<Data<C, V>>()
+            .counting_module
+            ._proposal_votes(&proposal_id)?;
+        Ok(proposal_vote)
+    }
+
+    default fn voter_conviction(
+        &self,
+        proposal_id: ProposalId,
+        account: AccountId,
+    ) -> Option<Conviction> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._voter_conviction(account, proposal_id)
+    }
+
+    default fn conviction_token(&self) -> AccountId {
+        self.data::<Data<C, V>>().counting_module._token()
+    }
+
+    default fn lock_period(&self) -> BlockNumber {
+        self.data::<Data<C, V>>().counting_module._lock_period()
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    fn _init_conviction(&mut self, token: AccountId, lock_period: BlockNumber);
+
+    fn _token(&self) -> AccountId;
+
+    fn _lock_period(&self) -> BlockNumber;
+
+    fn _voter_conviction(
+        &self,
+        account: AccountId,
+        proposal_id: ProposalId,
+    ) -> Option<Conviction>;
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError>;
+
+    /// Lock the weight of the voter in the token from the current block for the lock periods of
+    /// the conviction, nothing is locked without conviction. The lock fails if the weight exceeds
+    /// the balance of the voter (e.g. it includes delegated votes).
+    fn _lock_weight(
+        &self,
+        account: &AccountId,
+        weight: u64,
+        conviction: Conviction,
+    ) -> Result<(), CountingError>;
+}
+
+impl Internal for Counting {
+    fn _init_conviction(&mut self, token: AccountId, lock_period: BlockNumber) {
+        self.token = token;
+        self.lock_period = lock_period;
+    }
+
+    fn _token(&self) -> AccountId {
+        self.token
+    }
+
+    fn _lock_period(&self) -> BlockNumber {
+        self.lock_period
+    }
+
+    fn _voter_conviction(
+        &self,
+        account: AccountId,
+        proposal_id: ProposalId,
+    ) -> Option<Conviction> {
+        self.voter_convictions.get(&(account, proposal_id))
+    }
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError> {
+        if let Some(proposal_vote) = self.proposal_votes.get(proposal_id) {
+            Ok(proposal_vote)
+        } else {
+            Err(CountingSimpleError::NoProposal)
+        }
+    }
+
+    fn _lock_weight(
+        &self,
+        account: &AccountId,
+        weight: u64,
+        conviction: Conviction,
+    ) -> Result<(), CountingError> {
+        let lock_periods = conviction.lock_periods();
+        if lock_periods == 0 {
+            return Ok(())
+        }
+
+        let until = ink::env::block_number::<ink::env::DefaultEnvironment>()
+            .saturating_add(self.lock_period.saturating_mul(lock_periods));
+        match PSP22VotesRef::lock_builder(
+            &self.token,
+            *account,
+            Balance::from(weight),
+            until,
+        )
+        .try_invoke()
+        {
+            Ok(Ok(Ok(()))) => Ok(()),
+            _ => Err(CountingError::TokenLockFailed),
+        }
+    }
+}
@@ -0,0 +1,222 @@
+pub use crate::{
+    governance::modules::{
+        governor_counting_fractional,
+        governor_counting_fractional::Internal as _,
+    },
+    traits::{
+        errors::{
+            CountingError,
+            CountingSimpleError,
+        },
+        governance::modules::counting_fractional::*,
+    },
+};
+
+use crate::{
+    governance::governor::*,
+    governor::modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        BlockNumber,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+use ink::storage::traits::{
+    AutoStorableHint,
+    ManualKey,
+    Storable,
+    StorableHint,
+};
+use scale::DecodeAll;
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Counting);
+
+/// Counting storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Counting {
+    pub proposal_votes: Mapping<ProposalId, ProposalVote>,
+    pub used_weight: Mapping<(AccountId, ProposalId), u64>,
+    pub _reserved: Option<()>,
+}
+
+impl Counter for Counting {
+    default fn _counting_mode(&self) -> String {
+        String::from("support=bravo,fractional&quorum=for,abstain&params=fractional")
+    }
+
+    default fn _quorum_reached(
+        &self,
+        proposal_id: &ProposalId,
+        quorum: Option<u64>,
+    ) -> Result<bool, CountingError> {
+        let proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        Ok(quorum.map_or(false, |quorum| {
+            quorum
+                <= proposal_votes
+                    .for_votes
+                    .saturating_add(proposal_votes.abstain_votes)
+        }))
+    }
+
+    default fn _vote_succeeded(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, CountingError> {
+        let proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        Ok(proposal_votes.for_votes > proposal_votes.against_votes)
+    }
+
+    default fn _count_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+        support: u8,
+        weight: u64,
+        params: &[u8],
+    ) -> Result<(), CountingError> {
+        let used_weight = self._used_weight(*account, *proposal_id);
+
+        let vote = if params.is_empty() {
+            // Nominal vote: the whole weight goes to the support vote type.
+            if used_weight > 0 {
+                return Err(CountingError::VoteAlreadyCast)
+            }
+
+            let mut vote = ProposalVote::default();
+            match support.try_into()? {
+                VoteType::Against => vote.against_votes = weight,
+                VoteType::For => vote.for_votes = weight,
+                VoteType::Abstain => vote.abstain_votes = weight,
+            }
+            vote
+        } else {
+            ProposalVote::decode_all(&mut &params[..])
+                .map_err(|_| CountingError::InvalidParams)?
+        };
+
+        let new_used_weight = vote
+            .against_votes
+            .checked_add(vote.for_votes)
+            .and_then(|votes| votes.checked_add(vote.abstain_votes))
+            .and_then(|votes| votes.checked_add(used_weight))
+            .filter(|new_used_weight| *new_used_weight <= weight)
+            .ok_or(CountingError::VoteWeightExceeded)?;
+
+        if new_used_weight == used_weight {
+            return Err(CountingError::EmptyVote)
+        }
+
+        let proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        let proposal_votes = ProposalVote {
+            against_votes: proposal_votes
+                .against_votes
+                .checked_add(vote.against_votes)
+                .ok_or(CountingError::VotesOverflow)?,
+            for_votes: proposal_votes
+                .for_votes
+                .checked_add(vote.for_votes)
+                .ok_or(CountingError::VotesOverflow)?,
+            abstain_votes: proposal_votes
+                .abstain_votes
+                .checked_add(vote.abstain_votes)
+                .ok_or(CountingError::VotesOverflow)?,
+        };
+
+        self.used_weight
+            .insert(&(*account, *proposal_id), &new_used_weight);
+        self.proposal_votes.insert(proposal_id, &proposal_votes);
+
+        Ok(())
+    }
+}
+
+impl<T, C, V> CountingFractional for T
+where
+    C: Counter + Internal,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>,
+{
+    default fn quorum(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<u64, CountingSimpleError> {
+        Ok(governor::Internal::_quorum(self, block_number)?)
+    }
+
+    default fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._used_weight(account, proposal_id)
+            > 0
+    }
+
+    default fn used_weight(&self, proposal_id: ProposalId, account: AccountId) -> u64 {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._used_weight(account, proposal_id)
+    }
+
+    default fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError> {
+        let proposal_vote = self
+            .data::<Data<C, V>>()
+            .counting_module
+            ._proposal_votes(&proposal_id)?;
+        Ok(proposal_vote)
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    fn _used_weight(&self, account: AccountId, proposal_id: ProposalId) -> u64;
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError>;
+}
+
+impl Internal for Counting {
+    fn _used_weight(&self, account: AccountId, proposal_id: ProposalId) -> u64 {
+        self.used_weight
+            .get(&(account, proposal_id))
+            .unwrap_or_default()
+    }
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError> {
+        if let Some(proposal_vote) = self.proposal_votes.get(proposal_id) {
+            Ok(proposal_vote)
+        } else {
+            Err(CountingSimpleError::NoProposal)
+        }
+    }
+}
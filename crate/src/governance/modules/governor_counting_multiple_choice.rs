@@ -0,0 +1,261 @@
+pub use crate::{
+    governance::modules::{
+        governor_counting_multiple_choice,
+        governor_counting_multiple_choice::Internal as _,
+    },
+    traits::{
+        errors::CountingError,
+        governance::modules::counting_multiple_choice::*,
+    },
+};
+
+use crate::{
+    governance::governor::*,
+    governor::modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+use ink::{
+    prelude::vec::Vec,
+    storage::traits::{
+        AutoStorableHint,
+        ManualKey,
+        Storable,
+        StorableHint,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        BlockNumber,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Counting);
+
+/// Counting storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Counting {
+    /// Map every proposal to its options
+    pub proposal_options: Mapping<ProposalId, Vec<String>>,
+    /// Map every proposal to the votes of its options, in the order of the options
+    pub option_votes: Mapping<ProposalId, Vec<u64>>,
+    pub has_voted: Mapping<(AccountId, ProposalId), bool>,
+    pub _reserved: Option<()>,
+}
+
+impl Counter for Counting {
+    default fn _counting_mode(&self) -> String {
+        String::from("support=option&quorum=option")
+    }
+
+    default fn _quorum_reached(
+        &self,
+        proposal_id: &ProposalId,
+        quorum: Option<u64>,
+    ) -> Result<bool, CountingError> {
+        let winning_votes = self
+            .option_votes
+            .get(proposal_id)
+            .unwrap_or_default()
+            .into_iter()
+            .max()
+            .unwrap_or_default();
+        Ok(quorum.map_or(false, |quorum| quorum <= winning_votes))
+    }
+
+    default fn _vote_succeeded(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, CountingError> {
+        Ok(self._winning_option(proposal_id).is_some())
+    }
+
+    default fn _count_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+        support: u8,
+        weight: u64,
+        _params: &[u8],
+    ) -> Result<(), CountingError> {
+        if self._has_voted(*account, *proposal_id) {
+            return Err(CountingError::VoteAlreadyCast)
+        }
+
+        let mut option_votes = self.option_votes.get(proposal_id).unwrap_or_default();
+        let votes = option_votes
+            .get_mut(usize::from(support))
+            .ok_or(CountingError::InvalidVoteType)?;
+        *votes = votes
+            .checked_add(weight)
+            .ok_or(CountingError::VotesOverflow)?;
+
+        self.has_voted.insert(&(*account, *proposal_id), &true);
+        self.option_votes.insert(proposal_id, &option_votes);
+
+        Ok(())
+    }
+}
+
+impl<T, C, V> CountingMultipleChoice for T
+where
+    C: Counter + Internal,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>,
+{
+    default fn propose_with_options(
+        &mut self,
+        proposal: Proposal,
+        description: String,
+        options: Vec<String>,
+    ) -> Result<ProposalId, GovernorError> {
+        if options.len() < 2 || options.len() > usize::from(u8::MAX) + 1 {
+            return Err(GovernorError::InvalidProposalOptions)
+        }
+
+        let proposal_id = self.propose(proposal, description)?;
+
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._set_proposal_options(&proposal_id, options);
+
+        Ok(proposal_id)
+    }
+
+    default fn quorum(&self, block_number: BlockNumber) -> Result<u64, GovernorError> {
+        governor::Internal::_quorum(self, block_number)
+    }
+
+    default fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._has_voted(account, proposal_id)
+    }
+
+    default fn proposal_options(&self, proposal_id: ProposalId) -> Vec<String> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._proposal_options(&proposal_id)
+    }
+
+    default fn proposal_counting_mode(&self, proposal_id: ProposalId) -> String {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._proposal_counting_mode(&proposal_id)
+    }
+
+    default fn option_votes(&self, proposal_id: ProposalId) -> Vec<u64> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._option_votes(&proposal_id)
+    }
+
+    default fn winning_option(&self, proposal_id: ProposalId) -> Option<u8> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._winning_option(&proposal_id)
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool;
+
+    /// Store the options of a newly created proposal, every option starts with zero votes.
+    fn _set_proposal_options(&mut self, proposal_id: &ProposalId, options: Vec<String>);
+
+    fn _proposal_options(&self, proposal_id: &ProposalId) -> Vec<String>;
+
+    /// The counting mode followed by the percent-encoded options of a proposal.
+    fn _proposal_counting_mode(&self, proposal_id: &ProposalId) -> String;
+
+    fn _option_votes(&self, proposal_id: &ProposalId) -> Vec<u64>;
+
+    fn _winning_option(&self, proposal_id: &ProposalId) -> Option<u8>;
+}
+
+impl Internal for Counting {
+    fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool {
+        self.has_voted
+            .get(&(account, proposal_id))
+            .unwrap_or_default()
+    }
+
+    fn _set_proposal_options(&mut self, proposal_id: &ProposalId, options: Vec<String>) {
+        let option_votes: Vec<u64> = options.iter().map(|_| 0).collect();
+
+        self.option_votes.insert(proposal_id, &option_votes);
+        self.proposal_options.insert(proposal_id, &options);
+    }
+
+    fn _proposal_options(&self, proposal_id: &ProposalId) -> Vec<String> {
+        self.proposal_options.get(proposal_id).unwrap_or_default()
+    }
+
+    fn _proposal_counting_mode(&self, proposal_id: &ProposalId) -> String {
+        const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+        let mut counting_mode = self._counting_mode();
+        counting_mode.extend_from_slice(b"&options=");
+
+        for (index, option) in self._proposal_options(proposal_id).iter().enumerate() {
+            if index > 0 {
+                counting_mode.push(b',');
+            }
+            for byte in option.iter() {
+                match byte {
+                    b'%' | b'&' | b'=' | b',' => {
+                        counting_mode.extend_from_slice(&[
+                            b'%',
+                            HEX_DIGITS[usize::from(byte >> 4)],
+                            HEX_DIGITS[usize::from(byte & 0x0F)],
+                        ])
+                    }
+                    _ => counting_mode.push(*byte),
+                }
+            }
+        }
+
+        counting_mode
+    }
+
+    fn _option_votes(&self, proposal_id: &ProposalId) -> Vec<u64> {
+        self.option_votes.get(proposal_id).unwrap_or_default()
+    }
+
+    fn _winning_option(&self, proposal_id: &ProposalId) -> Option<u8> {
+        let option_votes = self._option_votes(proposal_id);
+        let winning_votes = option_votes.iter().copied().max().unwrap_or_default();
+
+        let mut winners = option_votes
+            .iter()
+            .enumerate()
+            .filter(|(_, votes)| **votes == winning_votes);
+
+        match (winners.next(), winners.next()) {
+            (Some((index, _)), None) if winning_votes > 0 => u8::try_from(index).ok(),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,269 @@
+pub use crate::{
+    governance::modules::{
+        governor_counting_optimistic,
+        governor_counting_optimistic::Internal as _,
+    },
+    traits::{
+        errors::{
+            CountingError,
+            CountingSimpleError,
+        },
+        governance::modules::counting_optimistic::*,
+    },
+};
+
+use crate::{
+    governance::governor::*,
+    governor::modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+use openbrush::{
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        BlockNumber,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+use ink::storage::traits::{
+    AutoStorableHint,
+    ManualKey,
+    Storable,
+    StorableHint,
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Counting);
+
+/// Counting storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Counting {
+    pub proposal_votes: Mapping<ProposalId, ProposalVote>,
+    pub has_voted: Mapping<(AccountId, ProposalId), bool>,
+    /// The number of against votes that a proposal must exceed to be vetoed
+    pub veto_threshold: u64,
+    /// The accounts allowed to create optimistic proposals
+    pub optimistic_proposers: Mapping<AccountId, bool>,
+    pub _reserved: Option<()>,
+}
+
+impl Counter for Counting {
+    default fn _counting_mode(&self) -> String {
+        String::from("support=bravo&quorum=none&success=against<=threshold")
+    }
+
+    default fn _quorum_reached(
+        &self,
+        _proposal_id: &ProposalId,
+        _quorum: Option<u64>,
+    ) -> Result<bool, CountingError> {
+        Ok(true)
+    }
+
+    default fn _vote_succeeded(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, CountingError> {
+        Ok(!self._vote_vetoed(proposal_id)?)
+    }
+
+    default fn _proposal_created(
+        &mut self,
+        proposer: &AccountId,
+        _proposal_id: &ProposalId,
+        _proposal: &Proposal,
+    ) -> Result<(), CountingError> {
+        if !self._is_optimistic_proposer(proposer) {
+            return Err(CountingError::GovernorError(
+                GovernorError::NotOptimisticProposer,
+            ))
+        }
+        Ok(())
+    }
+
+    default fn _vote_vetoed(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, CountingError> {
+        let proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        Ok(proposal_votes.against_votes > self.veto_threshold)
+    }
+
+    default fn _count_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+        support: u8,
+        weight: u64,
+        _params: &[u8],
+    ) -> Result<(), CountingError> {
+        if self._has_voted(*account, *proposal_id) {
+            return Err(CountingError::VoteAlreadyCast)
+        }
+
+        let mut proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        let votes = match support.try_into()? {
+            VoteType::Against => &mut proposal_votes.against_votes,
+            VoteType::For => &mut proposal_votes.for_votes,
+            VoteType::Abstain => &mut proposal_votes.abstain_votes,
+        };
+        *votes = votes
+            .checked_add(weight)
+            .ok_or(CountingError::VotesOverflow)?;
+
+        self.has_voted.insert(&(*account, *proposal_id), &true);
+        self.proposal_votes.insert(proposal_id, &proposal_votes);
+
+        Ok(())
+    }
+}
+
+impl<T, C, V> CountingOptimistic for T
+where
+    C: Counter + Internal,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>,
+{
+    default fn quorum(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<u64, CountingSimpleError> {
+        Ok(governor::Internal::_quorum(self, block_number)?)
+    }
+
+    default fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._has_voted(account, proposal_id)
+    }
+
+    default fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError> {
+        let proposal_vote = self
+            .data::<Data<C, V>>()
+            .counting_module
+            ._proposal_votes(&proposal_id)?;
+        Ok(proposal_vote)
+    }
+
+    default fn veto_threshold(&self) -> u64 {
+        self.data::<Data<C, V>>().counting_module._veto_threshold()
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn set_veto_threshold(
+        &mut self,
+        threshold: u64,
+    ) -> Result<(), GovernorError> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._set_veto_threshold(threshold);
+        Ok(())
+    }
+
+    default fn is_optimistic_proposer(&self, account: AccountId) -> bool {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._is_optimistic_proposer(&account)
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn set_optimistic_proposer(
+        &mut self,
+        account: AccountId,
+        allowed: bool,
+    ) -> Result<(), GovernorError> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._set_optimistic_proposer(&account, allowed);
+        Ok(())
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    fn _init_optimistic(&mut self, veto_threshold: u64, proposers: &[AccountId]);
+
+    fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool;
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError>;
+
+    fn _veto_threshold(&self) -> u64;
+
+    fn _set_veto_threshold(&mut self, threshold: u64);
+
+    fn _is_optimistic_proposer(&self, account: &AccountId) -> bool;
+
+    fn _set_optimistic_proposer(&mut self, account: &AccountId, allowed: bool);
+}
+
+impl Internal for Counting {
+    fn _init_optimistic(&mut self, veto_threshold: u64, proposers: &[AccountId]) {
+        self._set_veto_threshold(veto_threshold);
+        for proposer in proposers.iter() {
+            self._set_optimistic_proposer(proposer, true);
+        }
+    }
+
+    fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool {
+        self.has_voted
+            .get(&(account, proposal_id))
+            .unwrap_or_default()
+    }
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError> {
+        if let Some(proposal_vote) = self.proposal_votes.get(proposal_id) {
+            Ok(proposal_vote)
+        } else {
+            Err(CountingSimpleError::NoProposal)
+        }
+    }
+
+    fn _veto_threshold(&self) -> u64 {
+        self.veto_threshold
+    }
+
+    fn _set_veto_threshold(&mut self, threshold: u64) {
+        self.veto_threshold = threshold;
+    }
+
+    fn _is_optimistic_proposer(&self, account: &AccountId) -> bool {
+        self.optimistic_proposers.get(account).unwrap_or_default()
+    }
+
+    fn _set_optimistic_proposer(&mut self, account: &AccountId, allowed: bool) {
+        if allowed {
+            self.optimistic_proposers.insert(account, &true);
+        } else {
+            self.optimistic_proposers.remove(account);
+        }
+    }
+}
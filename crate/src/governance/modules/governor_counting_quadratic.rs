@@ -0,0 +1,293 @@
+pub use crate::{
+    governance::modules::{
+        governor_counting_quadratic,
+        governor_counting_quadratic::Internal as _,
+    },
+    traits::{
+        errors::{
+            CountingError,
+            CountingSimpleError,
+        },
+        governance::modules::counting_quadratic::*,
+    },
+};
+
+use crate::{
+    governance::governor::*,
+    governor::modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        BlockNumber,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+use ink::storage::traits::{
+    AutoStorableHint,
+    ManualKey,
+    Storable,
+    StorableHint,
+};
+use scale::DecodeAll;
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Counting);
+
+/// Counting storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Counting {
+    pub proposal_votes: Mapping<ProposalId, ProposalVote>,
+    pub voter_votes: Mapping<(AccountId, ProposalId), ProposalVote>,
+    /// Map every proposal to the credits spent on its for and abstain votes
+    pub proposal_turnout: Mapping<ProposalId, u64>,
+    pub _reserved: Option<()>,
+}
+
+impl Counter for Counting {
+    default fn _counting_mode(&self) -> String {
+        String::from("support=bravo,quadratic&quorum=for,abstain&params=quadratic")
+    }
+
+    default fn _quorum_reached(
+        &self,
+        proposal_id: &ProposalId,
+        quorum: Option<u64>,
+    ) -> Result<bool, CountingError> {
+        Ok(quorum.map_or(false, |quorum| {
+            quorum <= self.proposal_turnout.get(proposal_id).unwrap_or_default()
+        }))
+    }
+
+    default fn _vote_succeeded(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, CountingError> {
+        let proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        Ok(proposal_votes.for_votes > proposal_votes.against_votes)
+    }
+
+    default fn _count_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+        support: u8,
+        weight: u64,
+        params: &[u8],
+    ) -> Result<(), CountingError> {
+        let voter_votes = self._voter_votes(*account, *proposal_id);
+
+        let vote = if params.is_empty() {
+            // Nominal vote: all the credits are spent on the support vote type.
+            if voter_votes != ProposalVote::default() {
+                return Err(CountingError::VoteAlreadyCast)
+            }
+
+            let votes = self._quadratic_votes(weight);
+            let mut vote = ProposalVote::default();
+            match support.try_into()? {
+                VoteType::Against => vote.against_votes = votes,
+                VoteType::For => vote.for_votes = votes,
+                VoteType::Abstain => vote.abstain_votes = votes,
+            }
+            vote
+        } else {
+            ProposalVote::decode_all(&mut &params[..])
+                .map_err(|_| CountingError::InvalidParams)?
+        };
+
+        // The cost is computed on the total votes of the account.
+        let new_voter_votes = self
+            ._add_votes(&voter_votes, &vote)
+            .filter(|votes| {
+                let credits = self._voice_credits(votes);
+                matches!(credits, Some(credits) if credits <= weight)
+            })
+            .ok_or(CountingError::VoteWeightExceeded)?;
+
+        let proposal_votes = self
+            ._add_votes(
+                &self.proposal_votes.get(proposal_id).unwrap_or_default(),
+                &vote,
+            )
+            .ok_or(CountingError::VotesOverflow)?;
+
+        // The credits of the account are already spent, so its turnout only grows.
+        let turnout = self
+            .proposal_turnout
+            .get(proposal_id)
+            .unwrap_or_default()
+            .saturating_add(
+                self._turnout_credits(&new_voter_votes)
+                    - self._turnout_credits(&voter_votes),
+            );
+
+        self.voter_votes
+            .insert(&(*account, *proposal_id), &new_voter_votes);
+        self.proposal_votes.insert(proposal_id, &proposal_votes);
+        self.proposal_turnout.insert(proposal_id, &turnout);
+
+        Ok(())
+    }
+}
+
+impl<T, C, V> CountingQuadratic for T
+where
+    C: Counter + Internal,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>,
+{
+    default fn quorum(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<u64, CountingSimpleError> {
+        Ok(governor::Internal::_quorum(self, block_number)?)
+    }
+
+    default fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._voter_votes(account, proposal_id)
+            != ProposalVote::default()
+    }
+
+    default fn voter_votes(
+        &self,
+        proposal_id: ProposalId,
+        account: AccountId,
+    ) -> ProposalVote {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._voter_votes(account, proposal_id)
+    }
+
+    default fn used_credits(&self, proposal_id: ProposalId, account: AccountId) -> u64 {
+        let counting_module = &self.data::<Data<C, V>>().counting_module;
+        counting_module
+            ._voice_credits(&counting_module._voter_votes(account, proposal_id))
+            .unwrap_or(u64::MAX)
+    }
+
+    default fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError> {
+        let proposal_vote = self
+            .data::<Data<C, V>>()
+            .counting_module
+            ._proposal_votes(&proposal_id)?;
+        Ok(proposal_vote)
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    fn _voter_votes(&self, account: AccountId, proposal_id: ProposalId) -> ProposalVote;
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError>;
+
+    /// Returns the votes that can be cast spending `credits`, the integer square root of the
+    /// credits.
+    fn _quadratic_votes(&self, credits: u64) -> u64;
+
+    /// Returns the credits needed to cast `votes`, the sum of the squares of the votes of every
+    /// vote type, None on overflow.
+    fn _voice_credits(&self, votes: &ProposalVote) -> Option<u64>;
+
+    /// Returns the credits spent on the for and abstain votes of `votes`, they are counted by the
+    /// quorum.
+    fn _turnout_credits(&self, votes: &ProposalVote) -> u64;
+
+    /// Returns the sum of two votes, None on overflow.
+    fn _add_votes(
+        &self,
+        votes: &ProposalVote,
+        other: &ProposalVote,
+    ) -> Option<ProposalVote>;
+}
+
+impl Internal for Counting {
+    fn _voter_votes(&self, account: AccountId, proposal_id: ProposalId) -> ProposalVote {
+        self.voter_votes
+            .get(&(account, proposal_id))
+            .unwrap_or_default()
+    }
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError> {
+        if let Some(proposal_vote) = self.proposal_votes.get(proposal_id) {
+            Ok(proposal_vote)
+        } else {
+            Err(CountingSimpleError::NoProposal)
+        }
+    }
+
+    fn _quadratic_votes(&self, credits: u64) -> u64 {
+        if credits < 2 {
+            return credits
+        }
+
+        // Newton's method, the first estimate is above the root and it decreases to it.
+        let mut root = credits / 2 + 1;
+        let mut next = (root + credits / root) / 2;
+        while next < root {
+            root = next;
+            next = (root + credits / root) / 2;
+        }
+        root
+    }
+
+    fn _voice_credits(&self, votes: &ProposalVote) -> Option<u64> {
+        votes
+            .against_votes
+            .checked_mul(votes.against_votes)?
+            .checked_add(votes.for_votes.checked_mul(votes.for_votes)?)?
+            .checked_add(votes.abstain_votes.checked_mul(votes.abstain_votes)?)
+    }
+
+    fn _turnout_credits(&self, votes: &ProposalVote) -> u64 {
+        self._voice_credits(&ProposalVote {
+            against_votes: 0,
+            ..votes.clone()
+        })
+        .unwrap_or(u64::MAX)
+    }
+
+    fn _add_votes(
+        &self,
+        votes: &ProposalVote,
+        other: &ProposalVote,
+    ) -> Option<ProposalVote> {
+        Some(ProposalVote {
+            against_votes: votes.against_votes.checked_add(other.against_votes)?,
+            for_votes: votes.for_votes.checked_add(other.for_votes)?,
+            abstain_votes: votes.abstain_votes.checked_add(other.abstain_votes)?,
+        })
+    }
+}
@@ -0,0 +1,490 @@
+pub use crate::{
+    governance::modules::{
+        governor_counting_ranked_choice,
+        governor_counting_ranked_choice::Internal as _,
+    },
+    traits::{
+        errors::CountingError,
+        governance::modules::counting_ranked_choice::*,
+    },
+};
+
+use crate::{
+    governance::governor::*,
+    governor::modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+use ink::{
+    prelude::vec::Vec,
+    storage::traits::{
+        AutoStorableHint,
+        ManualKey,
+        Storable,
+        StorableHint,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        BlockNumber,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+use scale::DecodeAll;
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Counting);
+
+/// Counting storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Counting {
+    /// Map every proposal to its options
+    pub proposal_options: Mapping<ProposalId, Vec<String>>,
+    /// Map every proposal to the method used to tally its votes
+    pub ranking_modes: Mapping<ProposalId, RankingMode>,
+    /// Map every proposal and index to a ballot cast on it
+    pub ballots: Mapping<(ProposalId, u32), RankedBallot>,
+    /// Map every proposal to the number of ballots cast on it
+    pub ballot_counts: Mapping<ProposalId, u32>,
+    /// Map every proposal to the weight of the ballots cast on it
+    pub total_votes: Mapping<ProposalId, u64>,
+    /// Map every proposal tallied with Borda to the points of its options
+    pub borda_points: Mapping<ProposalId, Vec<u64>>,
+    /// Map every tallied proposal to its winning options
+    pub winners: Mapping<ProposalId, Vec<u8>>,
+    pub has_voted: Mapping<(AccountId, ProposalId), bool>,
+    pub _reserved: Option<()>,
+}
+
+impl Counter for Counting {
+    default fn _counting_mode(&self) -> String {
+        String::from("support=ranked&quorum=participation&params=preferences&options=proposal_options")
+    }
+
+    default fn _quorum_reached(
+        &self,
+        proposal_id: &ProposalId,
+        quorum: Option<u64>,
+    ) -> Result<bool, CountingError> {
+        Ok(quorum.map_or(false, |quorum| {
+            quorum <= self.total_votes.get(proposal_id).unwrap_or_default()
+        }))
+    }
+
+    default fn _vote_succeeded(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, CountingError> {
+        let winners = match self.winners.get(proposal_id) {
+            Some(winners) => winners,
+            // The Borda points are counted with every vote, while the ballots are read only by
+            // `tally`, so an instant-runoff proposal that is not tallied is not successful.
+            None if self._ranking_mode(proposal_id) == Some(RankingMode::Borda) => {
+                self._winning_options(proposal_id)?
+            }
+            None => return Ok(false),
+        };
+        Ok(winners.len() == 1)
+    }
+
+    default fn _count_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+        support: u8,
+        weight: u64,
+        params: &[u8],
+    ) -> Result<(), CountingError> {
+        if self._has_voted(*account, *proposal_id) {
+            return Err(CountingError::VoteAlreadyCast)
+        }
+
+        let preferences = if params.is_empty() {
+            Vec::from([support])
+        } else {
+            Vec::<u8>::decode_all(&mut &params[..])
+                .map_err(|_| CountingError::InvalidParams)?
+        };
+
+        let options = self._proposal_options(proposal_id).len();
+        for (position, option) in preferences.iter().enumerate() {
+            if usize::from(*option) >= options || preferences[..position].contains(option)
+            {
+                return Err(CountingError::InvalidVoteType)
+            }
+        }
+        if preferences.is_empty() {
+            return Err(CountingError::InvalidParams)
+        }
+
+        let total_votes = self
+            .total_votes
+            .get(proposal_id)
+            .unwrap_or_default()
+            .checked_add(weight)
+            .ok_or(CountingError::VotesOverflow)?;
+
+        if let Some(mut points) = self.borda_points.get(proposal_id) {
+            for (position, option) in preferences.iter().enumerate() {
+                let ballot_points = u64::try_from(options - 1 - position)
+                    .ok()
+                    .and_then(|ranked_below| ranked_below.checked_mul(weight))
+                    .ok_or(CountingError::VotesOverflow)?;
+                let option_points = &mut points[usize::from(*option)];
+                *option_points = option_points
+                    .checked_add(ballot_points)
+                    .ok_or(CountingError::VotesOverflow)?;
+            }
+            self.borda_points.insert(proposal_id, &points);
+        }
+
+        let index = self.ballot_counts.get(proposal_id).unwrap_or_default();
+        self.ballots.insert(
+            &(*proposal_id, index),
+            &RankedBallot {
+                preferences,
+                weight,
+            },
+        );
+        self.ballot_counts.insert(proposal_id, &(index + 1));
+        self.total_votes.insert(proposal_id, &total_votes);
+        self.has_voted.insert(&(*account, *proposal_id), &true);
+
+        Ok(())
+    }
+}
+
+impl<T, C, V> CountingRankedChoice for T
+where
+    C: Counter + Internal,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>,
+{
+    default fn propose_with_options(
+        &mut self,
+        proposal: Proposal,
+        description: String,
+        options: Vec<String>,
+        mode: RankingMode,
+    ) -> Result<ProposalId, GovernorError> {
+        if options.len() < 2 || options.len() > usize::from(u8::MAX) + 1 {
+            return Err(GovernorError::InvalidProposalOptions)
+        }
+
+        let proposal_id = self.propose(proposal, description)?;
+
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._set_proposal_options(&proposal_id, options, mode);
+
+        Ok(proposal_id)
+    }
+
+    default fn quorum(&self, block_number: BlockNumber) -> Result<u64, GovernorError> {
+        governor::Internal::_quorum(self, block_number)
+    }
+
+    default fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._has_voted(account, proposal_id)
+    }
+
+    default fn proposal_options(&self, proposal_id: ProposalId) -> Vec<String> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._proposal_options(&proposal_id)
+    }
+
+    default fn ranking_mode(&self, proposal_id: ProposalId) -> Option<RankingMode> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._ranking_mode(&proposal_id)
+    }
+
+    default fn winning_options(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<u8>, GovernorError> {
+        Ok(self
+            .data::<Data<C, V>>()
+            .counting_module
+            ._winning_options(&proposal_id)?)
+    }
+
+    default fn rounds(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<Round>, GovernorError> {
+        let (rounds, _) = self
+            .data::<Data<C, V>>()
+            .counting_module
+            ._tally(&proposal_id)?;
+        Ok(rounds)
+    }
+
+    default fn tally(
+        &mut self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<u8>, GovernorError> {
+        if Self::env().block_number() <= self.proposal_deadline(proposal_id)? {
+            return Err(GovernorError::ProposalNotFinished)
+        }
+
+        Ok(self
+            .data::<Data<C, V>>()
+            .counting_module
+            ._store_tally(&proposal_id)?)
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool;
+
+    /// Store the options of a newly created proposal and the method used to tally its votes.
+    fn _set_proposal_options(
+        &mut self,
+        proposal_id: &ProposalId,
+        options: Vec<String>,
+        mode: RankingMode,
+    );
+
+    fn _proposal_options(&self, proposal_id: &ProposalId) -> Vec<String>;
+
+    fn _ranking_mode(&self, proposal_id: &ProposalId) -> Option<RankingMode>;
+
+    /// Returns all the ballots cast on a proposal.
+    fn _ballots(&self, proposal_id: &ProposalId) -> Vec<RankedBallot>;
+
+    /// Returns the stored winning options of a proposal, or tallies them if the proposal is not
+    /// tallied yet.
+    fn _winning_options(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<Vec<u8>, CountingError>;
+
+    /// Tally a proposal and store its winning options.
+    fn _store_tally(
+        &mut self,
+        proposal_id: &ProposalId,
+    ) -> Result<Vec<u8>, CountingError>;
+
+    /// Tally the ballots of a proposal with its ranking mode. Returns the results of every round
+    /// and the indexes of the winning options.
+    fn _tally(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<(Vec<Round>, Vec<u8>), CountingError>;
+
+    /// Instant-runoff tally: every round the options with the fewest votes are eliminated and
+    /// their ballots are transferred to the next preference still in the running, until an
+    /// option has the majority of the votes or all the remaining options are tied.
+    fn _instant_runoff(
+        &self,
+        options: usize,
+        ballots: &[RankedBallot],
+    ) -> Result<(Vec<Round>, Vec<u8>), CountingError>;
+
+    /// Borda tally: a single round where every option receives from each ballot, weighted, a
+    /// number of points equal to the number of options ranked below it (the options that are not
+    /// ranked are below all the ranked ones).
+    fn _borda(&self, points: Vec<u64>) -> (Vec<Round>, Vec<u8>);
+}
+
+impl Internal for Counting {
+    fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool {
+        self.has_voted
+            .get(&(account, proposal_id))
+            .unwrap_or_default()
+    }
+
+    fn _set_proposal_options(
+        &mut self,
+        proposal_id: &ProposalId,
+        options: Vec<String>,
+        mode: RankingMode,
+    ) {
+        if mode == RankingMode::Borda {
+            let points: Vec<u64> = options.iter().map(|_| 0).collect();
+            self.borda_points.insert(proposal_id, &points);
+        }
+        self.proposal_options.insert(proposal_id, &options);
+        self.ranking_modes.insert(proposal_id, &mode);
+    }
+
+    fn _proposal_options(&self, proposal_id: &ProposalId) -> Vec<String> {
+        self.proposal_options.get(proposal_id).unwrap_or_default()
+    }
+
+    fn _ranking_mode(&self, proposal_id: &ProposalId) -> Option<RankingMode> {
+        self.ranking_modes.get(proposal_id)
+    }
+
+    fn _ballots(&self, proposal_id: &ProposalId) -> Vec<RankedBallot> {
+        (0..self.ballot_counts.get(proposal_id).unwrap_or_default())
+            .filter_map(|index| self.ballots.get(&(*proposal_id, index)))
+            .collect()
+    }
+
+    fn _winning_options(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<Vec<u8>, CountingError> {
+        if let Some(winners) = self.winners.get(proposal_id) {
+            return Ok(winners)
+        }
+
+        let (_, winners) = self._tally(proposal_id)?;
+        Ok(winners)
+    }
+
+    fn _store_tally(
+        &mut self,
+        proposal_id: &ProposalId,
+    ) -> Result<Vec<u8>, CountingError> {
+        let winners = self._winning_options(proposal_id)?;
+        self.winners.insert(proposal_id, &winners);
+        Ok(winners)
+    }
+
+    fn _tally(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<(Vec<Round>, Vec<u8>), CountingError> {
+        match self._ranking_mode(proposal_id).unwrap_or_default() {
+            RankingMode::InstantRunoff => {
+                let options = self._proposal_options(proposal_id).len();
+                self._instant_runoff(options, &self._ballots(proposal_id))
+            }
+            RankingMode::Borda => {
+                Ok(self._borda(self.borda_points.get(proposal_id).unwrap_or_default()))
+            }
+        }
+    }
+
+    fn _instant_runoff(
+        &self,
+        options: usize,
+        ballots: &[RankedBallot],
+    ) -> Result<(Vec<Round>, Vec<u8>), CountingError> {
+        let mut running: Vec<bool> = (0..options).map(|_| true).collect();
+        let mut rounds = Vec::new();
+
+        loop {
+            let mut tallies: Vec<u64> = (0..options).map(|_| 0).collect();
+            let mut total: u64 = 0;
+            for ballot in ballots {
+                // Exhausted ballots, with no preference still in the running, are not counted.
+                if let Some(option) = ballot
+                    .preferences
+                    .iter()
+                    .find(|option| running[usize::from(**option)])
+                {
+                    let votes = &mut tallies[usize::from(*option)];
+                    *votes = votes
+                        .checked_add(ballot.weight)
+                        .ok_or(CountingError::VotesOverflow)?;
+                    total = total
+                        .checked_add(ballot.weight)
+                        .ok_or(CountingError::VotesOverflow)?;
+                }
+            }
+
+            let running_tallies: Vec<(u8, u64)> = tallies
+                .iter()
+                .enumerate()
+                .filter(|(option, _)| running[*option])
+                .map(|(option, votes)| (option as u8, *votes))
+                .collect();
+
+            if total == 0 {
+                rounds.push(Round {
+                    tallies,
+                    eliminated: Vec::new(),
+                });
+                return Ok((rounds, Vec::new()))
+            }
+
+            if let Some((winner, _)) = running_tallies
+                .iter()
+                .find(|(_, votes)| *votes > total - *votes)
+            {
+                rounds.push(Round {
+                    tallies,
+                    eliminated: Vec::new(),
+                });
+                return Ok((rounds, Vec::from([*winner])))
+            }
+
+            let fewest = running_tallies
+                .iter()
+                .map(|(_, votes)| *votes)
+                .min()
+                .unwrap_or_default();
+            let eliminated: Vec<u8> = running_tallies
+                .iter()
+                .filter(|(_, votes)| *votes == fewest)
+                .map(|(option, _)| *option)
+                .collect();
+
+            // All the remaining options are tied.
+            if eliminated.len() == running_tallies.len() {
+                rounds.push(Round {
+                    tallies,
+                    eliminated: Vec::new(),
+                });
+                return Ok((rounds, eliminated))
+            }
+
+            for option in eliminated.iter() {
+                running[usize::from(*option)] = false;
+            }
+            rounds.push(Round {
+                tallies,
+                eliminated,
+            });
+        }
+    }
+
+    fn _borda(&self, points: Vec<u64>) -> (Vec<Round>, Vec<u8>) {
+        let most = points.iter().copied().max().unwrap_or_default();
+        let winners = if most > 0 {
+            points
+                .iter()
+                .enumerate()
+                .filter(|(_, option_points)| **option_points == most)
+                .map(|(option, _)| option as u8)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        (
+            Vec::from([Round {
+                tallies: points,
+                eliminated: Vec::new(),
+            }]),
+            winners,
+        )
+    }
+}
@@ -20,20 +20,33 @@ use crate::{
     },
 };
 use openbrush::{
+    modifiers,
     storage::Mapping,
     traits::{
         AccountId,
+        BlockNumber,
+        Hash,
         OccupiedStorage,
         Storage,
         String,
     },
 };
 
-use ink::storage::traits::{
-    AutoStorableHint,
-    ManualKey,
-    Storable,
-    StorableHint,
+use ink::{
+    env::hash::{
+        Blake2x256,
+        HashOutput,
+    },
+    prelude::vec::Vec,
+    storage::{
+        traits::{
+            AutoStorableHint,
+            ManualKey,
+            Storable,
+            StorableHint,
+        },
+        Lazy,
+    },
 };
 
 /// Unique storage key
@@ -44,20 +57,46 @@ pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Counting);
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Counting {
     pub proposal_votes: Mapping<ProposalId, ProposalVote>,
+    /// Map every account and proposal to whether the account has a vote on the proposal, the
+    /// entry is kept with `false` once the vote is retracted
     pub has_voted: Mapping<(AccountId, ProposalId), bool>,
+    /// Map every account and proposal to the vote cast by the account
+    pub receipts: Mapping<(AccountId, ProposalId), VoteReceipt>,
+    /// Whether a new vote replaces the previous vote of an account
+    pub vote_changing: Lazy<bool>,
+    /// Map every proposal and index of first vote to the voter
+    pub voters: Mapping<(ProposalId, u32), AccountId>,
+    /// Map every proposal to the number of its voters
+    pub voter_counts: Mapping<ProposalId, u32>,
+    /// Map every class of proposals to its success threshold
+    pub class_thresholds: Mapping<u8, SuccessThreshold>,
+    /// Map every proposal to the success threshold of its strictest class
+    pub proposal_thresholds: Mapping<ProposalId, SuccessThreshold>,
+    /// Map every callee and selector to the class of the transactions that call it
+    pub transaction_classes: Mapping<(AccountId, [u8; 4]), u8>,
     pub _reserved: Option<()>,
 }
 
 impl Counter for Counting {
+    default fn _counting_mode(&self) -> String {
+        String::from("support=bravo&quorum=for,abstain")
+    }
+
     default fn _quorum_reached(
         &self,
         proposal_id: &ProposalId,
+        quorum: Option<u64>,
     ) -> Result<bool, CountingError> {
         let proposal_votes = self
             .proposal_votes
             .get(proposal_id)
             .ok_or(CountingError::Custom(String::from("Proposal not found")))?;
-        Ok(self._quorum() <= (proposal_votes.for_votes + proposal_votes.abstain_votes))
+        Ok(quorum.map_or(false, |quorum| {
+            quorum
+                <= proposal_votes
+                    .for_votes
+                    .saturating_add(proposal_votes.abstain_votes)
+        }))
     }
 
     default fn _vote_succeeded(
@@ -68,7 +107,34 @@ impl Counter for Counting {
             .proposal_votes
             .get(proposal_id)
             .ok_or(CountingError::Custom(String::from("Proposal not found")))?;
-        Ok(proposal_votes.for_votes > proposal_votes.against_votes)
+        let threshold = self._success_threshold(proposal_id);
+
+        let for_votes = u128::from(proposal_votes.for_votes);
+        let non_abstain_votes = for_votes + u128::from(proposal_votes.against_votes);
+        Ok(proposal_votes.for_votes > proposal_votes.against_votes
+            && for_votes * u128::from(threshold.denominator)
+                >= non_abstain_votes * u128::from(threshold.numerator))
+    }
+
+    default fn _proposal_created(
+        &mut self,
+        _proposer: &AccountId,
+        proposal_id: &ProposalId,
+        proposal: &Proposal,
+    ) -> Result<(), CountingError> {
+        let threshold = self._proposal_class_threshold(proposal);
+        self.proposal_thresholds.insert(proposal_id, &threshold);
+        Ok(())
+    }
+
+    default fn _vote_of(
+        &self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+    ) -> Option<(u8, u64)> {
+        self.receipts
+            .get(&(*account, *proposal_id))
+            .map(|receipt| (receipt.support, receipt.weight))
     }
 
     default fn _count_vote(
@@ -77,35 +143,55 @@ impl Counter for Counting {
         account: &AccountId,
         support: u8,
         weight: u64,
-        _params: &[u8],
+        params: &[u8],
     ) -> Result<(), CountingError> {
         let mut proposal_votes: ProposalVote = Default::default();
         if let Some(proposal) = self.proposal_votes.get(proposal_id) {
             proposal_votes = proposal;
         }
-        let has_voted = self._has_voted(*account, *proposal_id);
-        if has_voted {
-            return Err(CountingError::VoteAlreadyCast)
+        if self._has_voted(*account, *proposal_id) {
+            // The votes cast before the receipts were stored cannot be changed
+            let receipt = match self.receipts.get(&(*account, *proposal_id)) {
+                Some(receipt) if self._vote_changing() => receipt,
+                _ => return Err(CountingError::VoteAlreadyCast),
+            };
+
+            // Remove the previous vote before counting the new one
+            let old_votes = match receipt.support.try_into()? {
+                VoteType::For => &mut proposal_votes.for_votes,
+                VoteType::Against => &mut proposal_votes.against_votes,
+                VoteType::Abstain => &mut proposal_votes.abstain_votes,
+            };
+            *old_votes = old_votes.saturating_sub(receipt.weight);
         }
-        self.has_voted.insert(&(*account, *proposal_id), &true);
 
-        let updated_votes: ProposalVote = match support.try_into() {
-            Ok(VoteType::For) => {
-                proposal_votes.for_votes += weight;
-                proposal_votes
-            }
-            Ok(VoteType::Against) => {
-                proposal_votes.against_votes += weight;
-                proposal_votes
-            }
-            Ok(VoteType::Abstain) => {
-                proposal_votes.abstain_votes += weight;
-                proposal_votes
-            }
-            Err(err) => return Err(err),
+        let votes = match support.try_into()? {
+            VoteType::For => &mut proposal_votes.for_votes,
+            VoteType::Against => &mut proposal_votes.against_votes,
+            VoteType::Abstain => &mut proposal_votes.abstain_votes,
         };
+        *votes = votes
+            .checked_add(weight)
+            .ok_or(CountingError::VotesOverflow)?;
 
-        self.proposal_votes.insert(proposal_id, &updated_votes);
+        // The voters keep the position of their first vote, also after a retracted vote
+        if self.has_voted.get(&(*account, *proposal_id)).is_none() {
+            let voter_count = self._voter_count(proposal_id);
+            self.voters.insert(&(*proposal_id, voter_count), account);
+            self.voter_counts.insert(proposal_id, &(voter_count + 1));
+        }
+
+        let mut params_hash = <Blake2x256 as HashOutput>::Type::default();
+        ink::env::hash_bytes::<Blake2x256>(params, &mut params_hash);
+        let receipt = VoteReceipt {
+            support,
+            weight,
+            params_hash: Hash::from(params_hash),
+        };
+
+        self.has_voted.insert(&(*account, *proposal_id), &true);
+        self.receipts.insert(&(*account, *proposal_id), &receipt);
+        self.proposal_votes.insert(proposal_id, &proposal_votes);
 
         Ok(())
     }
@@ -130,8 +216,11 @@ where
     T: Storage<governor::Data<C, V>>,
     T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>,
 {
-    default fn quorum(&self) -> u64 {
-        self.data::<Data<C, V>>().counting_module._quorum()
+    default fn quorum(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<u64, CountingSimpleError> {
+        Ok(governor::Internal::_quorum(self, block_number)?)
     }
 
     default fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
@@ -150,31 +239,184 @@ where
             ._proposal_votes(&proposal_id)?;
         Ok(proposal_vote)
     }
+
+    default fn get_receipt(
+        &self,
+        proposal_id: ProposalId,
+        account: AccountId,
+    ) -> Option<VoteReceipt> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._receipt(&proposal_id, &account)
+    }
+
+    default fn voter_count(&self, proposal_id: ProposalId) -> u32 {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._voter_count(&proposal_id)
+    }
+
+    default fn voters(
+        &self,
+        proposal_id: ProposalId,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(AccountId, VoteReceipt)> {
+        let counting_module = &self.data::<Data<C, V>>().counting_module;
+        let end = offset
+            .saturating_add(limit)
+            .min(counting_module._voter_count(&proposal_id));
+
+        (offset..end)
+            .filter_map(|index| {
+                let voter = counting_module._voter(&proposal_id, index)?;
+                let receipt = counting_module._receipt(&proposal_id, &voter)?;
+                Some((voter, receipt))
+            })
+            .collect()
+    }
+
+    default fn transaction_class(&self, callee: AccountId, selector: [u8; 4]) -> u8 {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._transaction_class(&callee, &selector)
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn set_transaction_class(
+        &mut self,
+        callee: AccountId,
+        selector: [u8; 4],
+        class: u8,
+    ) -> Result<(), GovernorError> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._set_transaction_class(&callee, &selector, class);
+        Ok(())
+    }
+
+    default fn class_success_threshold(&self, class: u8) -> SuccessThreshold {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._class_success_threshold(class)
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn set_class_success_threshold(
+        &mut self,
+        class: u8,
+        threshold: SuccessThreshold,
+    ) -> Result<(), GovernorError> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._set_class_success_threshold(class, threshold)
+    }
+
+    default fn success_threshold(&self, proposal_id: ProposalId) -> SuccessThreshold {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._success_threshold(&proposal_id)
+    }
+
+    default fn vote_changing(&self) -> bool {
+        self.data::<Data<C, V>>().counting_module._vote_changing()
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn set_vote_changing(&mut self, enabled: bool) -> Result<(), GovernorError> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._set_vote_changing(enabled);
+        Ok(())
+    }
+
+    default fn retract_vote(
+        &mut self,
+        proposal_id: ProposalId,
+    ) -> Result<(), GovernorError> {
+        match self.state(proposal_id)? {
+            ProposalState::Active => {}
+            _ => return Err(GovernorError::ProposalNotActive),
+        }
+
+        let voter = Self::env().caller();
+        let receipt = self
+            .data::<Data<C, V>>()
+            .counting_module
+            ._retract_vote(&proposal_id, &voter)?;
+
+        governor::Internal::_emit_vote_retracted(
+            self,
+            voter,
+            proposal_id,
+            receipt.support,
+            receipt.weight,
+        );
+        Ok(())
+    }
 }
 
 /// Internal methods that perfom the logics of the contract
 pub trait Internal {
-    fn _quorum(&self) -> u64;
-
     fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool;
 
     fn _proposal_votes(
         &self,
         proposal_id: &ProposalId,
     ) -> Result<ProposalVote, CountingSimpleError>;
+
+    fn _receipt(
+        &self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+    ) -> Option<VoteReceipt>;
+
+    fn _voter_count(&self, proposal_id: &ProposalId) -> u32;
+
+    /// Returns the voter of a proposal at the index of first vote.
+    fn _voter(&self, proposal_id: &ProposalId, index: u32) -> Option<AccountId>;
+
+    fn _class_success_threshold(&self, class: u8) -> SuccessThreshold;
+
+    fn _set_class_success_threshold(
+        &mut self,
+        class: u8,
+        threshold: SuccessThreshold,
+    ) -> Result<(), GovernorError>;
+
+    fn _transaction_class(&self, callee: &AccountId, selector: &[u8; 4]) -> u8;
+
+    fn _set_transaction_class(
+        &mut self,
+        callee: &AccountId,
+        selector: &[u8; 4],
+        class: u8,
+    );
+
+    /// Returns the strictest success threshold of the classes of the transactions of a proposal,
+    /// the threshold of the class `0` if the proposal has no transactions.
+    fn _proposal_class_threshold(&self, proposal: &Proposal) -> SuccessThreshold;
+
+    /// Returns the success threshold stored by a proposal at its creation.
+    fn _success_threshold(&self, proposal_id: &ProposalId) -> SuccessThreshold;
+
+    fn _vote_changing(&self) -> bool;
+
+    fn _set_vote_changing(&mut self, enabled: bool);
+
+    /// Remove the vote of an account from the votes of a proposal and returns its receipt.
+    fn _retract_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+    ) -> Result<VoteReceipt, GovernorError>;
 }
 
 impl Internal for Counting {
-    fn _quorum(&self) -> u64 {
-        1
-    }
-
     fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool {
-        if let Some(vote) = self.has_voted.get(&(account, proposal_id)) {
-            vote
-        } else {
-            false
-        }
+        self.has_voted
+            .get(&(account, proposal_id))
+            .unwrap_or_default()
     }
 
     fn _proposal_votes(
@@ -187,4 +429,123 @@ impl Internal for Counting {
             Err(CountingSimpleError::NoProposal)
         }
     }
+
+    fn _receipt(
+        &self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+    ) -> Option<VoteReceipt> {
+        self.receipts.get(&(*account, *proposal_id))
+    }
+
+    fn _voter_count(&self, proposal_id: &ProposalId) -> u32 {
+        self.voter_counts.get(proposal_id).unwrap_or_default()
+    }
+
+    fn _voter(&self, proposal_id: &ProposalId, index: u32) -> Option<AccountId> {
+        self.voters.get(&(*proposal_id, index))
+    }
+
+    fn _class_success_threshold(&self, class: u8) -> SuccessThreshold {
+        self.class_thresholds.get(&class).unwrap_or_default()
+    }
+
+    fn _set_class_success_threshold(
+        &mut self,
+        class: u8,
+        threshold: SuccessThreshold,
+    ) -> Result<(), GovernorError> {
+        if threshold.denominator == 0
+            || threshold.numerator > threshold.denominator
+            || u128::from(threshold.numerator) * 2 < u128::from(threshold.denominator)
+        {
+            return Err(GovernorError::InvalidSuccessThreshold)
+        }
+
+        self.class_thresholds.insert(&class, &threshold);
+        Ok(())
+    }
+
+    fn _transaction_class(&self, callee: &AccountId, selector: &[u8; 4]) -> u8 {
+        self.transaction_classes
+            .get(&(*callee, *selector))
+            .unwrap_or_default()
+    }
+
+    fn _set_transaction_class(
+        &mut self,
+        callee: &AccountId,
+        selector: &[u8; 4],
+        class: u8,
+    ) {
+        if class == 0 {
+            self.transaction_classes.remove(&(*callee, *selector));
+        } else {
+            self.transaction_classes
+                .insert(&(*callee, *selector), &class);
+        }
+    }
+
+    fn _proposal_class_threshold(&self, proposal: &Proposal) -> SuccessThreshold {
+        if proposal.transactions.is_empty() {
+            return self._class_success_threshold(0)
+        }
+
+        proposal
+            .transactions
+            .iter()
+            .map(|transaction| {
+                let class =
+                    self._transaction_class(&transaction.callee, &transaction.selector);
+                self._class_success_threshold(class)
+            })
+            .max_by(|a, b| {
+                (u128::from(a.numerator) * u128::from(b.denominator))
+                    .cmp(&(u128::from(b.numerator) * u128::from(a.denominator)))
+            })
+            .unwrap_or_default()
+    }
+
+    fn _success_threshold(&self, proposal_id: &ProposalId) -> SuccessThreshold {
+        self.proposal_thresholds
+            .get(proposal_id)
+            .unwrap_or_default()
+    }
+
+    fn _vote_changing(&self) -> bool {
+        self.vote_changing.get().unwrap_or_default()
+    }
+
+    fn _set_vote_changing(&mut self, enabled: bool) {
+        self.vote_changing.set(&enabled);
+    }
+
+    fn _retract_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+    ) -> Result<VoteReceipt, GovernorError> {
+        if !self._vote_changing() {
+            return Err(GovernorError::VoteChangingDisabled)
+        }
+
+        let receipt = self
+            .receipts
+            .get(&(*account, *proposal_id))
+            .ok_or(GovernorError::VoteNotCast)?;
+
+        let mut proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        let votes = match receipt.support.try_into()? {
+            VoteType::For => &mut proposal_votes.for_votes,
+            VoteType::Against => &mut proposal_votes.against_votes,
+            VoteType::Abstain => &mut proposal_votes.abstain_votes,
+        };
+        *votes = votes.saturating_sub(receipt.weight);
+
+        self.has_voted.insert(&(*account, *proposal_id), &false);
+        self.receipts.remove(&(*account, *proposal_id));
+        self.proposal_votes.insert(proposal_id, &proposal_votes);
+
+        Ok(receipt)
+    }
 }
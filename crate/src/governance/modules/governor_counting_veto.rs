@@ -0,0 +1,241 @@
+pub use crate::{
+    governance::modules::{
+        governor_counting_veto,
+        governor_counting_veto::Internal as _,
+    },
+    traits::{
+        errors::{
+            CountingError,
+            CountingSimpleError,
+        },
+        governance::modules::counting_veto::*,
+    },
+};
+
+use crate::{
+    governance::governor::*,
+    governor::modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+use openbrush::{
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        BlockNumber,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+use ink::storage::traits::{
+    AutoStorableHint,
+    ManualKey,
+    Storable,
+    StorableHint,
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Counting);
+
+/// Counting storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Counting {
+    pub proposal_votes: Mapping<ProposalId, VetoProposalVote>,
+    pub has_voted: Mapping<(AccountId, ProposalId), bool>,
+    /// The fraction of the votes that the no with veto votes must exceed to veto a proposal
+    pub veto_threshold: VetoThreshold,
+    pub _reserved: Option<()>,
+}
+
+impl Counter for Counting {
+    default fn _counting_mode(&self) -> String {
+        String::from("support=bravo,veto&quorum=for,abstain")
+    }
+
+    default fn _quorum_reached(
+        &self,
+        proposal_id: &ProposalId,
+        quorum: Option<u64>,
+    ) -> Result<bool, CountingError> {
+        let proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        Ok(quorum.map_or(false, |quorum| {
+            quorum
+                <= proposal_votes
+                    .for_votes
+                    .saturating_add(proposal_votes.abstain_votes)
+        }))
+    }
+
+    default fn _vote_succeeded(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, CountingError> {
+        let proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        let against_votes = u128::from(proposal_votes.against_votes)
+            + u128::from(proposal_votes.no_with_veto_votes);
+
+        Ok(!self._vote_vetoed(proposal_id)?
+            && u128::from(proposal_votes.for_votes) > against_votes)
+    }
+
+    default fn _vote_vetoed(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, CountingError> {
+        let proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        let threshold = self.veto_threshold;
+        let total_votes = u128::from(proposal_votes.against_votes)
+            + u128::from(proposal_votes.for_votes)
+            + u128::from(proposal_votes.abstain_votes)
+            + u128::from(proposal_votes.no_with_veto_votes);
+
+        Ok(u128::from(proposal_votes.no_with_veto_votes)
+            * u128::from(threshold.denominator)
+            > total_votes * u128::from(threshold.numerator))
+    }
+
+    default fn _count_vote(
+        &mut self,
+        proposal_id: &ProposalId,
+        account: &AccountId,
+        support: u8,
+        weight: u64,
+        _params: &[u8],
+    ) -> Result<(), CountingError> {
+        if self._has_voted(*account, *proposal_id) {
+            return Err(CountingError::VoteAlreadyCast)
+        }
+
+        let mut proposal_votes = self.proposal_votes.get(proposal_id).unwrap_or_default();
+        let votes = match support.try_into()? {
+            VetoVoteType::Against => &mut proposal_votes.against_votes,
+            VetoVoteType::For => &mut proposal_votes.for_votes,
+            VetoVoteType::Abstain => &mut proposal_votes.abstain_votes,
+            VetoVoteType::NoWithVeto => &mut proposal_votes.no_with_veto_votes,
+        };
+        *votes = votes
+            .checked_add(weight)
+            .ok_or(CountingError::VotesOverflow)?;
+
+        self.has_voted.insert(&(*account, *proposal_id), &true);
+        self.proposal_votes.insert(proposal_id, &proposal_votes);
+
+        Ok(())
+    }
+}
+
+impl<T, C, V> CountingVeto for T
+where
+    C: Counter + Internal,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>,
+{
+    default fn quorum(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<u64, CountingSimpleError> {
+        Ok(governor::Internal::_quorum(self, block_number)?)
+    }
+
+    default fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._has_voted(account, proposal_id)
+    }
+
+    default fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<VetoProposalVote, CountingSimpleError> {
+        let proposal_vote = self
+            .data::<Data<C, V>>()
+            .counting_module
+            ._proposal_votes(&proposal_id)?;
+        Ok(proposal_vote)
+    }
+
+    default fn vetoed(&self, proposal_id: ProposalId) -> bool {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._vote_vetoed(&proposal_id)
+            .unwrap_or_default()
+    }
+
+    default fn veto_threshold(&self) -> VetoThreshold {
+        self.data::<Data<C, V>>().counting_module.veto_threshold
+    }
+
+    #[modifiers(governor::only_governance())]
+    default fn set_veto_threshold(
+        &mut self,
+        threshold: VetoThreshold,
+    ) -> Result<(), GovernorError> {
+        self.data::<Data<C, V>>()
+            .counting_module
+            ._set_veto_threshold(threshold)
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool;
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<VetoProposalVote, CountingSimpleError>;
+
+    fn _set_veto_threshold(
+        &mut self,
+        threshold: VetoThreshold,
+    ) -> Result<(), GovernorError>;
+}
+
+impl Internal for Counting {
+    fn _has_voted(&self, account: AccountId, proposal_id: ProposalId) -> bool {
+        self.has_voted
+            .get(&(account, proposal_id))
+            .unwrap_or_default()
+    }
+
+    fn _proposal_votes(
+        &self,
+        proposal_id: &ProposalId,
+    ) -> Result<VetoProposalVote, CountingSimpleError> {
+        if let Some(proposal_vote) = self.proposal_votes.get(proposal_id) {
+            Ok(proposal_vote)
+        } else {
+            Err(CountingSimpleError::NoProposal)
+        }
+    }
+
+    fn _set_veto_threshold(
+        &mut self,
+        threshold: VetoThreshold,
+    ) -> Result<(), GovernorError> {
+        if threshold.denominator == 0 || threshold.numerator > threshold.denominator {
+            return Err(GovernorError::InvalidVetoThreshold)
+        }
+
+        self.veto_threshold = threshold;
+        Ok(())
+    }
+}
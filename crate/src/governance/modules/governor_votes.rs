@@ -0,0 +1,114 @@
+pub use crate::{
+    governance::modules::{
+        governor_votes,
+        governor_votes::Internal as _,
+    },
+    traits::governance::{
+        modules::votes::*,
+        utils::votes::*,
+    },
+};
+
+use crate::{
+    governance::governor::*,
+    governor::modules::{
+        counter::Counter,
+        voter::Voter,
+    },
+};
+
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+    OccupiedStorage,
+    Storage,
+    ZERO_ADDRESS,
+};
+
+use ink::storage::traits::{
+    AutoStorableHint,
+    ManualKey,
+    Storable,
+    StorableHint,
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Voting);
+
+/// Voting storage struct
+#[derive(Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Voting {
+    /// The token contract used as source of the voting weight
+    pub token: AccountId,
+    pub _reserved: Option<()>,
+}
+
+impl Default for Voting {
+    fn default() -> Self {
+        Voting {
+            token: ZERO_ADDRESS.into(),
+            _reserved: Default::default(),
+        }
+    }
+}
+
+/// Note: This module does not provide a quorum, it must be combined with an extension that does it
+/// (e.g. GovernorVotesQuorumFraction).
+impl Voter for Voting {
+    default fn _get_votes(
+        &self,
+        account: &AccountId,
+        block_number: BlockNumber,
+        _params: &[u8],
+    ) -> Option<u64> {
+        VotesRef::get_past_votes(&self.token, *account, block_number)
+            .ok()
+            .filter(|votes| *votes > 0)
+    }
+
+    default fn _get_past_total_supply(&self, block_number: BlockNumber) -> Option<u64> {
+        VotesRef::get_past_total_supply(&self.token, block_number).ok()
+    }
+}
+
+impl<T, C, V> GovernorVotes for T
+where
+    C: Counter,
+    C: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<719029772, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = C,
+        >,
+    V: Voter + Internal,
+    V: Storable
+        + StorableHint<ManualKey<{ governor::STORAGE_KEY }>>
+        + AutoStorableHint<
+            ManualKey<3230629697, ManualKey<{ governor::STORAGE_KEY }>>,
+            Type = V,
+        >,
+    T: Storage<governor::Data<C, V>>,
+    T: OccupiedStorage<{ governor::STORAGE_KEY }, WithData = governor::Data<C, V>>,
+{
+    default fn token(&self) -> AccountId {
+        self.data::<Data<C, V>>().voting_module._token()
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    fn _init_token(&mut self, token: AccountId);
+
+    fn _token(&self) -> AccountId;
+}
+
+impl Internal for Voting {
+    fn _init_token(&mut self, token: AccountId) {
+        self.token = token;
+    }
+
+    fn _token(&self) -> AccountId {
+        self.token
+    }
+}
@@ -73,6 +73,11 @@ impl Voter for Voting {
     ) -> Option<u64> {
         self.members.get(account)
     }
+
+    /// Note: In this module by default is 1 vote for simple group members without token involved
+    default fn _quorum(&self, _block_number: BlockNumber) -> Option<u64> {
+        Some(1)
+    }
 }
 
 /// Modifier which check that the function is called only through governance or by the admin of the
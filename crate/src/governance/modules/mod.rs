@@ -1,7 +1,43 @@
+/// Counting approval (counter) sub-module
+#[cfg(feature = "governor_counting_approval")]
+pub mod governor_counting_approval;
+
+/// Counting conviction (counter) sub-module
+#[cfg(feature = "governor_counting_conviction")]
+pub mod governor_counting_conviction;
+
+/// Counting fractional (counter) sub-module
+#[cfg(feature = "governor_counting_fractional")]
+pub mod governor_counting_fractional;
+
+/// Counting multiple choice (counter) sub-module
+#[cfg(feature = "governor_counting_multiple_choice")]
+pub mod governor_counting_multiple_choice;
+
+/// Counting optimistic (counter) sub-module
+#[cfg(feature = "governor_counting_optimistic")]
+pub mod governor_counting_optimistic;
+
+/// Counting quadratic (counter) sub-module
+#[cfg(feature = "governor_counting_quadratic")]
+pub mod governor_counting_quadratic;
+
+/// Counting ranked choice (counter) sub-module
+#[cfg(feature = "governor_counting_ranked_choice")]
+pub mod governor_counting_ranked_choice;
+
 /// Counting simple (counter) sub-module
 #[cfg(feature = "governor_counting_simple")]
 pub mod governor_counting_simple;
 
+/// Counting veto (counter) sub-module
+#[cfg(feature = "governor_counting_veto")]
+pub mod governor_counting_veto;
+
+/// Governor votes (voter) sub-module
+#[cfg(feature = "governor_votes")]
+pub mod governor_votes;
+
 /// Voting group (voter) sub-module
 #[cfg(feature = "governor_voting_group")]
 pub mod governor_voting_group;
@@ -0,0 +1,3 @@
+/// Timelock controller module
+#[cfg(feature = "timelock_controller")]
+pub mod timelock_controller;
@@ -0,0 +1,562 @@
+pub use crate::{
+    governance::utils::{
+        timelock_controller,
+        timelock_controller::Internal as _,
+    },
+    traits::{
+        errors::TimelockControllerError,
+        governance::utils::timelock_controller::*,
+    },
+};
+
+pub use openbrush::contracts::access_control::{
+    self,
+    members,
+    AccessControl,
+    AccessControlError,
+    RoleType,
+};
+
+use crate::traits::governance::{
+    CallInput,
+    CallOutput,
+};
+
+use ink::{
+    env::{
+        call::{
+            build_call,
+            Call,
+            ExecutionInput,
+        },
+        hash::Blake2x256,
+        CallFlags,
+        DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+};
+use openbrush::{
+    modifier_definition,
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Hash,
+        OccupiedStorage,
+        Storage,
+        Timestamp,
+        ZERO_ADDRESS,
+    },
+};
+
+/// Unique storage key
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(TimelockController);
+
+/// The timestamp that marks an operation as done
+pub const DONE_TIMESTAMP: Timestamp = 1;
+
+/// TimelockController upgradeable storage struct
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// The minimum delay for an operation to become valid
+    pub min_delay: Timestamp,
+    /// Map every operation to the timestamp at which it becomes ready
+    pub timestamps: Mapping<OperationId, Timestamp>,
+    pub _reserved: Option<()>,
+}
+
+/// Modifier which check that the caller has the role or that the role is granted to the zero
+/// address (open role)
+#[modifier_definition]
+pub fn only_role_or_open_role<T, M, F, R, E>(
+    instance: &mut T,
+    body: F,
+    role: RoleType,
+) -> Result<R, E>
+where
+    M: members::MembersManager,
+    T: Storage<access_control::Data<M>>,
+    T: OccupiedStorage<
+        { access_control::STORAGE_KEY },
+        WithData = access_control::Data<M>,
+    >,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<AccessControlError>,
+{
+    if !instance.has_role(role, ZERO_ADDRESS.into())
+        && !instance.has_role(role, T::env().caller())
+    {
+        return Err(AccessControlError::MissingRole.into())
+    }
+
+    body(instance)
+}
+
+impl<T, M> TimelockController for T
+where
+    M: members::MembersManager,
+    T: Storage<Data> + Storage<access_control::Data<M>>,
+    T: OccupiedStorage<STORAGE_KEY, WithData = Data>
+        + OccupiedStorage<
+            { access_control::STORAGE_KEY },
+            WithData = access_control::Data<M>,
+        >,
+{
+    default fn get_min_delay(&self) -> Timestamp {
+        self.data::<Data>().min_delay
+    }
+
+    default fn get_timestamp(&self, id: OperationId) -> Timestamp {
+        self.data::<Data>().timestamps.get(&id).unwrap_or_default()
+    }
+
+    default fn is_operation(&self, id: OperationId) -> bool {
+        self.get_timestamp(id) > 0
+    }
+
+    default fn is_operation_pending(&self, id: OperationId) -> bool {
+        self.get_timestamp(id) > DONE_TIMESTAMP
+    }
+
+    default fn is_operation_ready(&self, id: OperationId) -> bool {
+        let timestamp = self.get_timestamp(id);
+        timestamp > DONE_TIMESTAMP && timestamp <= Self::env().block_timestamp()
+    }
+
+    default fn is_operation_done(&self, id: OperationId) -> bool {
+        self.get_timestamp(id) == DONE_TIMESTAMP
+    }
+
+    default fn hash_operation(
+        &self,
+        transaction: Transaction,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+    ) -> OperationId {
+        self._hash_operation(&transaction, &predecessor, &salt)
+    }
+
+    default fn hash_operation_batch(
+        &self,
+        transactions: Vec<Transaction>,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+    ) -> OperationId {
+        self._hash_operation_batch(&transactions, &predecessor, &salt)
+    }
+
+    #[modifiers(access_control::only_role(PROPOSER_ROLE))]
+    default fn schedule(
+        &mut self,
+        transaction: Transaction,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+        delay: Timestamp,
+    ) -> Result<(), TimelockControllerError> {
+        let id = self._hash_operation(&transaction, &predecessor, &salt);
+
+        self._schedule(id, delay)?;
+
+        self._emit_call_scheduled(id, 0, transaction, predecessor, delay);
+
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(PROPOSER_ROLE))]
+    default fn schedule_batch(
+        &mut self,
+        transactions: Vec<Transaction>,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+        delay: Timestamp,
+    ) -> Result<(), TimelockControllerError> {
+        let id = self._hash_operation_batch(&transactions, &predecessor, &salt);
+
+        self._schedule(id, delay)?;
+
+        for (index, transaction) in transactions.into_iter().enumerate() {
+            self._emit_call_scheduled(id, index as u32, transaction, predecessor, delay);
+        }
+
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(CANCELLER_ROLE))]
+    default fn cancel(&mut self, id: OperationId) -> Result<(), TimelockControllerError> {
+        if !self.is_operation_pending(id) {
+            return Err(TimelockControllerError::OperationCannotBeCanceled)
+        }
+
+        self.data::<Data>().timestamps.remove(&id);
+
+        self._emit_cancelled(id);
+
+        Ok(())
+    }
+
+    #[modifiers(only_role_or_open_role(EXECUTOR_ROLE))]
+    default fn execute(
+        &mut self,
+        transaction: Transaction,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+    ) -> Result<(), TimelockControllerError> {
+        let id = self._hash_operation(&transaction, &predecessor, &salt);
+
+        self._before_call(&id, &predecessor)?;
+        self._call(&id, 0, &transaction)?;
+        self._after_call(&id)?;
+
+        Ok(())
+    }
+
+    #[modifiers(only_role_or_open_role(EXECUTOR_ROLE))]
+    default fn execute_batch(
+        &mut self,
+        transactions: Vec<Transaction>,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+    ) -> Result<(), TimelockControllerError> {
+        let id = self._hash_operation_batch(&transactions, &predecessor, &salt);
+
+        self._before_call(&id, &predecessor)?;
+        for (index, transaction) in transactions.iter().enumerate() {
+            self._call(&id, index as u32, transaction)?;
+        }
+        self._after_call(&id)?;
+
+        Ok(())
+    }
+
+    default fn update_delay(
+        &mut self,
+        new_delay: Timestamp,
+    ) -> Result<(), TimelockControllerError> {
+        if Self::env().caller() != Self::env().account_id() {
+            return Err(TimelockControllerError::CallerMustBeTimeLock)
+        }
+
+        self._set_min_delay(new_delay);
+
+        Ok(())
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_min_delay_change(&self, _old_delay: Timestamp, _new_delay: Timestamp);
+
+    fn _emit_call_scheduled(
+        &self,
+        _id: OperationId,
+        _index: u32,
+        _transaction: Transaction,
+        _predecessor: Option<OperationId>,
+        _delay: Timestamp,
+    );
+
+    fn _emit_call_executed(
+        &self,
+        _id: OperationId,
+        _index: u32,
+        _transaction: Transaction,
+    );
+
+    fn _emit_cancelled(&self, _id: OperationId);
+
+    /// Initializes the contract with the following parameters:
+    ///
+    /// - `admin`: optional account to be granted admin role; disable with `None`
+    /// - `min_delay`: initial minimum delay for operations
+    /// - `proposers`: accounts to be granted proposer and canceller roles
+    /// - `executors`: accounts to be granted executor role
+    ///
+    /// Note: The optional admin can aid with initial configuration of roles after deployment
+    /// without being subject to delay, but this role should be subsequently renounced in favor of
+    /// administration through timelocked proposals.
+    fn _init_with_roles(
+        &mut self,
+        admin: Option<AccountId>,
+        min_delay: Timestamp,
+        proposers: Vec<AccountId>,
+        executors: Vec<AccountId>,
+    );
+
+    fn _set_min_delay(&mut self, new_delay: Timestamp);
+
+    fn _hash_operation(
+        &self,
+        transaction: &Transaction,
+        predecessor: &Option<OperationId>,
+        salt: &Hash,
+    ) -> OperationId;
+
+    fn _hash_operation_batch(
+        &self,
+        transactions: &[Transaction],
+        predecessor: &Option<OperationId>,
+        salt: &Hash,
+    ) -> OperationId;
+
+    /// Schedule an operation that is to becomes valid after a given delay.
+    fn _schedule(
+        &mut self,
+        id: OperationId,
+        delay: Timestamp,
+    ) -> Result<(), TimelockControllerError>;
+
+    /// Checks before execution of an operation's calls.
+    fn _before_call(
+        &self,
+        id: &OperationId,
+        predecessor: &Option<OperationId>,
+    ) -> Result<(), TimelockControllerError>;
+
+    /// Checks after execution of an operation's calls.
+    fn _after_call(&mut self, id: &OperationId) -> Result<(), TimelockControllerError>;
+
+    /// Execute an operation's call.
+    ///
+    /// Emits a CallExecuted event.
+    fn _call(
+        &mut self,
+        id: &OperationId,
+        index: u32,
+        transaction: &Transaction,
+    ) -> Result<(), TimelockControllerError>;
+
+    /// Check the output of an operation's call.
+    ///
+    /// Note: The output of a called message that returns `Err` is decoded as a successful output,
+    /// by default it must be `()` or a `Result` (see `CallOutput::check_result`).
+    fn _check_call_output(
+        &self,
+        transaction: &Transaction,
+        output: &CallOutput,
+    ) -> Result<(), TimelockControllerError>;
+}
+
+impl<T, M> Internal for T
+where
+    M: members::MembersManager,
+    T: Storage<Data> + Storage<access_control::Data<M>>,
+    T: OccupiedStorage<STORAGE_KEY, WithData = Data>
+        + OccupiedStorage<
+            { access_control::STORAGE_KEY },
+            WithData = access_control::Data<M>,
+        >,
+{
+    default fn _emit_min_delay_change(
+        &self,
+        _old_delay: Timestamp,
+        _new_delay: Timestamp,
+    ) {
+    }
+
+    default fn _emit_call_scheduled(
+        &self,
+        _id: OperationId,
+        _index: u32,
+        _transaction: Transaction,
+        _predecessor: Option<OperationId>,
+        _delay: Timestamp,
+    ) {
+    }
+
+    default fn _emit_call_executed(
+        &self,
+        _id: OperationId,
+        _index: u32,
+        _transaction: Transaction,
+    ) {
+    }
+
+    default fn _emit_cancelled(&self, _id: OperationId) {}
+
+    default fn _init_with_roles(
+        &mut self,
+        admin: Option<AccountId>,
+        min_delay: Timestamp,
+        proposers: Vec<AccountId>,
+        executors: Vec<AccountId>,
+    ) {
+        access_control::Internal::_set_role_admin(
+            self,
+            TIMELOCK_ADMIN_ROLE,
+            TIMELOCK_ADMIN_ROLE,
+        );
+        access_control::Internal::_set_role_admin(
+            self,
+            PROPOSER_ROLE,
+            TIMELOCK_ADMIN_ROLE,
+        );
+        access_control::Internal::_set_role_admin(
+            self,
+            EXECUTOR_ROLE,
+            TIMELOCK_ADMIN_ROLE,
+        );
+        access_control::Internal::_set_role_admin(
+            self,
+            CANCELLER_ROLE,
+            TIMELOCK_ADMIN_ROLE,
+        );
+
+        // The timelock is self-administered
+        access_control::Internal::_setup_role(
+            self,
+            TIMELOCK_ADMIN_ROLE,
+            Self::env().account_id(),
+        );
+
+        if let Some(admin) = admin {
+            access_control::Internal::_setup_role(self, TIMELOCK_ADMIN_ROLE, admin);
+        }
+
+        for proposer in proposers {
+            access_control::Internal::_setup_role(self, PROPOSER_ROLE, proposer);
+            access_control::Internal::_setup_role(self, CANCELLER_ROLE, proposer);
+        }
+
+        for executor in executors {
+            access_control::Internal::_setup_role(self, EXECUTOR_ROLE, executor);
+        }
+
+        self._set_min_delay(min_delay);
+    }
+
+    default fn _set_min_delay(&mut self, new_delay: Timestamp) {
+        let old_delay = self.data::<Data>().min_delay;
+        self._emit_min_delay_change(old_delay, new_delay);
+
+        self.data::<Data>().min_delay = new_delay;
+    }
+
+    default fn _hash_operation(
+        &self,
+        transaction: &Transaction,
+        predecessor: &Option<OperationId>,
+        salt: &Hash,
+    ) -> OperationId {
+        let mut hash_data: Vec<u8> = Vec::new();
+
+        hash_data.append(&mut scale::Encode::encode(&transaction));
+        hash_data.append(&mut scale::Encode::encode(&predecessor));
+        hash_data.append(&mut scale::Encode::encode(&salt));
+
+        Hash::try_from(Self::env().hash_bytes::<Blake2x256>(&hash_data).as_ref()).unwrap()
+    }
+
+    default fn _hash_operation_batch(
+        &self,
+        transactions: &[Transaction],
+        predecessor: &Option<OperationId>,
+        salt: &Hash,
+    ) -> OperationId {
+        let mut hash_data: Vec<u8> = Vec::new();
+
+        hash_data.append(&mut scale::Encode::encode(&transactions));
+        hash_data.append(&mut scale::Encode::encode(&predecessor));
+        hash_data.append(&mut scale::Encode::encode(&salt));
+
+        Hash::try_from(Self::env().hash_bytes::<Blake2x256>(&hash_data).as_ref()).unwrap()
+    }
+
+    default fn _schedule(
+        &mut self,
+        id: OperationId,
+        delay: Timestamp,
+    ) -> Result<(), TimelockControllerError> {
+        if self.is_operation(id) {
+            return Err(TimelockControllerError::OperationAlreadyScheduled)
+        }
+        if delay < self.get_min_delay() {
+            return Err(TimelockControllerError::InsufficientDelay)
+        }
+
+        let timestamp = Self::env()
+            .block_timestamp()
+            .checked_add(delay)
+            .ok_or(TimelockControllerError::TimestampOverflow)?;
+        self.data::<Data>().timestamps.insert(&id, &timestamp);
+
+        Ok(())
+    }
+
+    default fn _before_call(
+        &self,
+        id: &OperationId,
+        predecessor: &Option<OperationId>,
+    ) -> Result<(), TimelockControllerError> {
+        if !self.is_operation_ready(*id) {
+            return Err(TimelockControllerError::OperationIsNotReady)
+        }
+        if let Some(predecessor) = predecessor {
+            if !self.is_operation_done(*predecessor) {
+                return Err(TimelockControllerError::MissingDependency)
+            }
+        }
+
+        Ok(())
+    }
+
+    default fn _after_call(
+        &mut self,
+        id: &OperationId,
+    ) -> Result<(), TimelockControllerError> {
+        if !self.is_operation_ready(*id) {
+            return Err(TimelockControllerError::OperationIsNotReady)
+        }
+
+        self.data::<Data>().timestamps.insert(id, &DONE_TIMESTAMP);
+
+        Ok(())
+    }
+
+    default fn _call(
+        &mut self,
+        id: &OperationId,
+        index: u32,
+        transaction: &Transaction,
+    ) -> Result<(), TimelockControllerError> {
+        // Flush the state into storage before the cross call.
+        // Because during cross call we can call this contract.
+        self.flush();
+        let result = build_call::<DefaultEnvironment>()
+            .call_type(
+                Call::new(transaction.callee)
+                    .gas_limit(0)
+                    .transferred_value(transaction.transferred_value),
+            )
+            .exec_input(
+                ExecutionInput::new(transaction.selector.into())
+                    .push_arg(CallInput(&transaction.input)),
+            )
+            .returns::<CallOutput>()
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .try_invoke()
+            .map_err(|_| TimelockControllerError::UnderlyingTransactionReverted);
+
+        // Load the state of the contract after the cross call.
+        self.load();
+
+        let output = result?
+            .map_err(|_| TimelockControllerError::UnderlyingTransactionReverted)?;
+        self._check_call_output(transaction, &output)?;
+
+        self._emit_call_executed(*id, index, transaction.clone());
+
+        Ok(())
+    }
+
+    default fn _check_call_output(
+        &self,
+        _transaction: &Transaction,
+        output: &CallOutput,
+    ) -> Result<(), TimelockControllerError> {
+        output
+            .check_result()
+            .map_err(|_| TimelockControllerError::UnderlyingTransactionReverted)
+    }
+}
@@ -14,7 +14,7 @@
 //! ```ignore
 //! [dependencies]
 //!
-//! ink        = { version = "~4.0.0", default-features = false }
+//! ink        = { version = "~4.2.0", default-features = false }
 //! openbrush  = { git = "https://github.com/727-Ventures/openbrush-contracts", default-features = false }
 //! scale      = { package = "parity-scale-codec", version = "3.4.0", default-features = false, features = ["derive"] }
 //! scale-info = { version = "2.3.1", default-features = false, features = ["derive"], optional = true }
@@ -22,7 +22,7 @@
 //! ink-governance = { version = "0.1.0", default-features = false, features = ["governor_group"] }
 //!
 //! [dev-dependencies]
-//! ink_e2e = { version = "~4.0.0" }
+//! ink_e2e = { version = "~4.2.0" }
 //!
 //! [features]
 //! default = ["std"]
@@ -43,20 +43,37 @@
 //! | Name | Trait definition | Traits default implementation |Crate Feature |  Description |
 //! | :-------- | :------- | :--------------| :------------| :-----|
 //! | governor  |  [Governor](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/governor.rs)  | [Governor](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/governor.rs)  |["governor"] | Core of the governance system.   |
-//! | counting_simple | [CountingSimple](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/counting_simple.rs)| [CountingSimple](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_counting_simple.rs) | ["counting_simple"] | Simple voting mechanism with 3 voting options: Against, For and Abstain.|
+//! | counting_simple | [CountingSimple](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/counting_simple.rs)| [CountingSimple](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_counting_simple.rs) | ["counting_simple"] | Simple voting mechanism with 3 voting options: Against, For and Abstain, with configurable supermajority thresholds.|
 //! | voting_group | [VotingGroup](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/voting_group.rs) | [VotingGroup](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_voting_group.rs)| ["voting_group"] | Extracts voting weight from a group of members controlled by an admin.
+//! | governor_votes | [GovernorVotes](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/votes.rs) | [GovernorVotes](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_votes.rs)| ["governor_votes"] | Extracts voting weight from a separate token contract implementing Votes.
+//! | counting_fractional | [CountingFractional](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/counting_fractional.rs)| [CountingFractional](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_counting_fractional.rs) | ["governor_counting_fractional"] | Voting mechanism that allows to split the weight of a vote between Against, For and Abstain.|
+//! | counting_multiple_choice | [CountingMultipleChoice](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/counting_multiple_choice.rs)| [CountingMultipleChoice](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_counting_multiple_choice.rs) | ["governor_counting_multiple_choice"] | Voting mechanism where the voters choose one of the options declared by the proposal.|
+//! | counting_ranked_choice | [CountingRankedChoice](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/counting_ranked_choice.rs)| [CountingRankedChoice](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_counting_ranked_choice.rs) | ["governor_counting_ranked_choice"] | Voting mechanism where the voters rank the options declared by the proposal, tallied with instant-runoff or Borda count.|
+//! | counting_approval | [CountingApproval](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/counting_approval.rs)| [CountingApproval](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_counting_approval.rs) | ["governor_counting_approval"] | Voting mechanism where the voters approve any subset of the options declared by the proposal and the options with the most votes win.|
+//! | counting_quadratic | [CountingQuadratic](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/counting_quadratic.rs)| [CountingQuadratic](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_counting_quadratic.rs) | ["governor_counting_quadratic"] | Voting mechanism where the weight of a voter is spent as voice credits and casting n votes costs n² credits.|
+//! | counting_veto | [CountingVeto](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/counting_veto.rs)| [CountingVeto](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_counting_veto.rs) | ["governor_counting_veto"] | Simple voting mechanism with a fourth voting option, NoWithVeto, that defeats the proposal when it exceeds a veto threshold.|
+//! | counting_conviction | [CountingConviction](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/counting_conviction.rs)| [CountingConviction](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_counting_conviction.rs) | ["governor_counting_conviction"] | Simple voting mechanism where the voters multiply their weight with a conviction in exchange for locking it in the PSP22Votes token.|
+//! | counting_optimistic | [CountingOptimistic](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/modules/counting_optimistic.rs)| [CountingOptimistic](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/modules/governor_counting_optimistic.rs) | ["governor_counting_optimistic"] | Optimistic voting mechanism where the proposals succeed unless their against votes exceed a veto threshold.|
 //!
 //! ## Extensions
 //!
 //! | Name | Trait definition | Traits default implementation |Crate Feature |  Description |
 //! | :-------- | :------- | :--------------| :------------| :-----|
 //! | governor_settings  |  [GovernorSettings](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/extensions/settings.rs)  | [GovernorSettings](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/extensions/governor_settings.rs)  |["governor_settings"] | Extension of Governor to update settings through governance.   |
+//! | governor_timelock_control  |  [GovernorTimelockControl](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/extensions/timelock_control.rs)  | [GovernorTimelockControl](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/extensions/governor_timelock_control.rs)  |["governor_timelock_control"] | Extension of Governor that binds the execution process to a TimelockController.   |
+//! | governor_storage  |  [GovernorStorage](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/extensions/storage.rs)  | [GovernorStorage](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/extensions/governor_storage.rs)  |["governor_storage"] | Extension of Governor that stores and enumerates the proposals on-chain.   |
+//! | governor_votes_quorum_fraction  |  [GovernorVotesQuorumFraction](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/extensions/votes_quorum_fraction.rs)  | [GovernorVotesQuorumFraction](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/extensions/governor_votes_quorum_fraction.rs)  |["governor_votes_quorum_fraction"] | Extension of Governor with a quorum expressed as a fraction of the total supply.   |
+//! | governor_prevent_late_quorum  |  [GovernorPreventLateQuorum](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/extensions/prevent_late_quorum.rs)  | [GovernorPreventLateQuorum](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/extensions/governor_prevent_late_quorum.rs)  |["governor_prevent_late_quorum"] | Extension of Governor that extends the voting period when quorum is reached late.   |
+//! | governor_proposal_deposit  |  [GovernorProposalDeposit](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/extensions/proposal_deposit.rs)  | [GovernorProposalDeposit](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/extensions/governor_proposal_deposit.rs)  |["governor_proposal_deposit"] | Extension of Governor that requires a deposit to propose, refunded after the vote or slashed if the proposal is vetoed.   |
+//! | governor_commit_reveal  |  [GovernorCommitReveal](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/extensions/commit_reveal.rs)  | [GovernorCommitReveal](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/extensions/governor_commit_reveal.rs)  |["governor_commit_reveal"] | Extension of Governor for secret ballots: the votes are committed during the voting period and revealed after it.   |
 //!
 //! ## Other Modules
 //!
 //! | Name | Trait definition | Traits default implementation |Crate Feature |  Description |
 //! | :-------- | :------- | :--------------| :------------| :-----|
 //! | psp22_votes  |  [PSP22Votes](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/token/psp22/extensions/votes.rs)  | [PSP22Votes](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/token/psp22/extensions/psp22_votes.rs)  |["psp22_votes"] | Extension of PSP22 to support voting and delegation.   |
+//! | timelock_controller  |  [TimelockController](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/governance/utils/timelock_controller.rs)  | [TimelockController](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/governance/utils/timelock_controller.rs)  |["timelock_controller"] | Contract module which acts as a timelocked controller with proposer, executor and canceller roles.   |
+//! | psp22_permit  |  [PSP22Permit](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/traits/token/psp22/extensions/permit.rs)  | [PSP22Permit](https://github.com/alessandro-baldassarre/ink-governance/blob/main/crate/src/token/psp22/extensions/psp22_permit.rs)  |["psp22_permit"] | Extension of PSP22Votes to approve allowances by signature.   |
 //!
 //! ## How to use
 //!
@@ -117,14 +134,65 @@ mod token;
 #[cfg(feature = "governor")]
 pub use governance::governor;
 
+#[cfg(feature = "governor_commit_reveal")]
+pub use governance::extensions::governor_commit_reveal;
+
+#[cfg(feature = "governor_prevent_late_quorum")]
+pub use governance::extensions::governor_prevent_late_quorum;
+
+#[cfg(feature = "governor_proposal_deposit")]
+pub use governance::extensions::governor_proposal_deposit;
+
 #[cfg(feature = "governor_settings")]
 pub use governance::extensions::governor_settings;
 
+#[cfg(feature = "governor_storage")]
+pub use governance::extensions::governor_storage;
+
+#[cfg(feature = "governor_timelock_control")]
+pub use governance::extensions::governor_timelock_control;
+
+#[cfg(feature = "governor_votes_quorum_fraction")]
+pub use governance::extensions::governor_votes_quorum_fraction;
+
+#[cfg(feature = "governor_counting_approval")]
+pub use governance::modules::governor_counting_approval;
+
+#[cfg(feature = "governor_counting_conviction")]
+pub use governance::modules::governor_counting_conviction;
+
+#[cfg(feature = "governor_counting_fractional")]
+pub use governance::modules::governor_counting_fractional;
+
+#[cfg(feature = "governor_counting_multiple_choice")]
+pub use governance::modules::governor_counting_multiple_choice;
+
+#[cfg(feature = "governor_counting_optimistic")]
+pub use governance::modules::governor_counting_optimistic;
+
+#[cfg(feature = "governor_counting_quadratic")]
+pub use governance::modules::governor_counting_quadratic;
+
+#[cfg(feature = "governor_counting_ranked_choice")]
+pub use governance::modules::governor_counting_ranked_choice;
+
 #[cfg(feature = "governor_counting_simple")]
 pub use governance::modules::governor_counting_simple;
 
+#[cfg(feature = "governor_counting_veto")]
+pub use governance::modules::governor_counting_veto;
+
 #[cfg(feature = "governor_voting_group")]
 pub use governance::modules::governor_voting_group;
 
+#[cfg(feature = "governor_votes")]
+pub use governance::modules::governor_votes;
+
+#[cfg(feature = "psp22_permit")]
+pub use token::psp22::extensions::psp22_permit;
+
 #[cfg(feature = "psp22_votes")]
 pub use token::psp22::extensions::psp22_votes;
+
+#[cfg(feature = "timelock_controller")]
+pub use governance::utils::timelock_controller;
@@ -0,0 +1,92 @@
+pub use crate::{
+    psp22_permit,
+    psp22_permit::Internal as _,
+    traits::token::psp22::extensions::permit::*,
+};
+
+use crate::{
+    psp22_votes,
+    traits::signature,
+};
+
+use psp22::Internal as _;
+
+use openbrush::{
+    contracts::psp22::*,
+    traits::{
+        AccountId,
+        Balance,
+        BlockNumber,
+        Hash,
+        OccupiedStorage,
+        Storage,
+        String,
+    },
+};
+
+impl<T> PSP22Permit for T
+where
+    T: Storage<psp22_votes::Data> + Storage<psp22::Data>,
+    T: OccupiedStorage<{ psp22_votes::STORAGE_KEY }, WithData = psp22_votes::Data>
+        + OccupiedStorage<{ psp22::STORAGE_KEY }, WithData = psp22::Data>,
+{
+    default fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: BlockNumber,
+        signature: Signature,
+    ) -> Result<(), PSP22PermitError> {
+        if Self::env().block_number() > deadline {
+            return Err(PSP22PermitError::SignatureExpired)
+        }
+
+        let permit = Permit {
+            owner,
+            spender,
+            value,
+            nonce: self
+                .data::<psp22_votes::Data>()
+                .nonces
+                .get(&owner)
+                .unwrap_or_default(),
+            deadline,
+        };
+
+        if !signature::verify(&owner, &self._hash_permit(&permit), &signature) {
+            return Err(PSP22PermitError::InvalidSignature)
+        }
+
+        psp22_votes::Internal::_use_nonce(self, &owner);
+
+        self._approve_from_to(owner, spender, value)?;
+
+        Ok(())
+    }
+
+    default fn domain_separator(&self) -> Hash {
+        Hash::from(signature::hash_payload(&(
+            String::from("PSP22Permit"),
+            Self::env().account_id(),
+        )))
+    }
+}
+
+/// Internal methods that perfom the logics of the contract
+pub trait Internal {
+    /// Returns the message that the owner signs off-chain to permit an allowance: the blake2x256
+    /// hash of the SCALE encoded tuple (`domain_separator`, `Permit`).
+    fn _hash_permit(&self, permit: &Permit) -> [u8; 32];
+}
+
+impl<T> Internal for T
+where
+    T: Storage<psp22_votes::Data> + Storage<psp22::Data>,
+    T: OccupiedStorage<{ psp22_votes::STORAGE_KEY }, WithData = psp22_votes::Data>
+        + OccupiedStorage<{ psp22::STORAGE_KEY }, WithData = psp22::Data>,
+{
+    default fn _hash_permit(&self, permit: &Permit) -> [u8; 32] {
+        signature::hash_payload(&(self.domain_separator(), permit))
+    }
+}
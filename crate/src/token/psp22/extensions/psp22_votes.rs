@@ -1,6 +1,9 @@
-use crate::traits::errors::{
-    PSP22VotesError,
-    VotesError,
+use crate::traits::{
+    errors::{
+        PSP22VotesError,
+        VotesError,
+    },
+    signature,
 };
 pub use crate::{
     psp22_votes,
@@ -46,6 +49,12 @@ pub struct Data {
     pub delegates: Mapping<AccountId, AccountId>,
     pub checkpoints: Mapping<AccountId, Vec<Checkpoint>>,
     pub total_supply_checkpoints: Vec<Checkpoint>,
+    /// Map every account to the nonce of its next signature
+    pub nonces: Mapping<AccountId, u64>,
+    /// The account allowed to lock the tokens of the holders
+    pub locker: Option<AccountId>,
+    /// Map every account to its locks that have not been removed yet
+    pub locks: Mapping<AccountId, Vec<Lock>>,
     pub _reserved: Option<()>,
 }
 
@@ -103,6 +112,49 @@ where
     }
 }
 
+impl<T> DelegateBySig for T
+where
+    T: Storage<Data> + Storage<psp22::Data>,
+    T: OccupiedStorage<{ STORAGE_KEY }, WithData = Data>
+        + OccupiedStorage<{ psp22::STORAGE_KEY }, WithData = psp22::Data>,
+{
+    default fn delegation_nonces(&self, account: AccountId) -> u64 {
+        self.data::<Data>().nonces.get(&account).unwrap_or_default()
+    }
+
+    default fn delegate_by_sig(
+        &mut self,
+        delegator: AccountId,
+        delegatee: AccountId,
+        nonce: u64,
+        expiry: BlockNumber,
+        signature: Signature,
+    ) -> Result<(), VotesError> {
+        if Self::env().block_number() > expiry {
+            return Err(VotesError::SignatureExpired)
+        }
+
+        if nonce != self.delegation_nonces(delegator) {
+            return Err(VotesError::InvalidNonce)
+        }
+
+        let delegation = Delegation {
+            delegatee,
+            nonce,
+            expiry,
+        };
+
+        if !signature::verify(&delegator, &self._hash_delegation(&delegation), &signature)
+        {
+            return Err(VotesError::InvalidSignature)
+        }
+
+        self._use_nonce(&delegator);
+
+        self._delegate(&delegator, &delegatee)
+    }
+}
+
 impl<T> PSP22Votes for T
 where
     T: Storage<Data> + Storage<psp22::Data>,
@@ -149,6 +201,44 @@ where
 
         Ok(len)
     }
+
+    default fn locker(&self) -> Option<AccountId> {
+        self.data::<Data>().locker
+    }
+
+    default fn set_locker(&mut self, locker: AccountId) -> Result<(), PSP22VotesError> {
+        if self.data::<Data>().locker != Some(Self::env().caller()) {
+            return Err(PSP22VotesError::NotLocker)
+        }
+
+        self._init_locker(locker);
+        Ok(())
+    }
+
+    default fn lock(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+        until: BlockNumber,
+    ) -> Result<(), PSP22VotesError> {
+        if self.data::<Data>().locker != Some(Self::env().caller()) {
+            return Err(PSP22VotesError::NotLocker)
+        }
+
+        self._lock(&account, amount, until)
+    }
+
+    default fn locks(&self, account: AccountId) -> Vec<Lock> {
+        self.data::<Data>().locks.get(&account).unwrap_or_default()
+    }
+
+    default fn locked_balance(&self, account: AccountId) -> Balance {
+        self._locked_balance(&account)
+    }
+
+    default fn unlock(&mut self, account: AccountId) -> Result<(), PSP22VotesError> {
+        self._unlock(&account)
+    }
 }
 
 /// Internal methods that perfom the logics of the contract
@@ -171,6 +261,17 @@ pub trait Internal {
         _new_balance: Balance,
     );
 
+    /// Emitted when tokens of an account are locked.
+    fn _emit_tokens_locked(
+        &self,
+        _account: AccountId,
+        _amount: Balance,
+        _until: BlockNumber,
+    );
+
+    /// Emitted when the expired locks of an account are removed.
+    fn _emit_tokens_unlocked(&self, _account: AccountId, _unlocked_amount: Balance);
+
     fn _get_checkpoints(
         &self,
         account: &AccountId,
@@ -178,6 +279,13 @@ pub trait Internal {
 
     fn _delegates(&self, account: &AccountId) -> Option<AccountId>;
 
+    /// Returns the message that the delegator signs off-chain to delegate by signature: the
+    /// blake2x256 hash of the SCALE encoded tuple (token `AccountId`, `Delegation`).
+    fn _hash_delegation(&self, delegation: &Delegation) -> [u8; 32];
+
+    /// Consume the current nonce of an account and return it.
+    fn _use_nonce(&mut self, account: &AccountId) -> u64;
+
     fn _get_votes(&self, account: &AccountId) -> Result<u64, VotesError>;
 
     fn _get_past_votes(
@@ -214,6 +322,32 @@ pub trait Internal {
         to: Option<&AccountId>,
         amount: &Balance,
     ) -> Result<(), PSP22VotesError>;
+
+    fn _init_locker(&mut self, locker: AccountId);
+
+    fn _lock(
+        &mut self,
+        account: &AccountId,
+        amount: Balance,
+        until: BlockNumber,
+    ) -> Result<(), PSP22VotesError>;
+
+    /// Remove the expired locks of an account.
+    ///
+    /// Emits a TokensUnlocked event.
+    fn _unlock(&mut self, account: &AccountId) -> Result<(), PSP22VotesError>;
+
+    /// Returns the amount of the largest lock of an account, expired locks included until they
+    /// are removed.
+    fn _locked_balance(&self, account: &AccountId) -> Balance;
+
+    /// Hook to call before a token transfer (or burn), it fails if the transfer exceeds the
+    /// balance of the sender that is not locked.
+    fn _before_token_transfer_locks(
+        &self,
+        from: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22VotesError>;
 }
 
 impl<T> Internal for T
@@ -238,6 +372,21 @@ where
     ) {
     }
 
+    default fn _emit_tokens_locked(
+        &self,
+        _account: AccountId,
+        _amount: Balance,
+        _until: BlockNumber,
+    ) {
+    }
+
+    default fn _emit_tokens_unlocked(
+        &self,
+        _account: AccountId,
+        _unlocked_amount: Balance,
+    ) {
+    }
+
     #[inline]
     default fn _get_checkpoints(
         &self,
@@ -255,6 +404,16 @@ where
         self.data::<Data>().delegates.get(account)
     }
 
+    default fn _hash_delegation(&self, delegation: &Delegation) -> [u8; 32] {
+        signature::hash_payload(&(Self::env().account_id(), delegation))
+    }
+
+    default fn _use_nonce(&mut self, account: &AccountId) -> u64 {
+        let nonce = self.data::<Data>().nonces.get(account).unwrap_or_default();
+        self.data::<Data>().nonces.insert(account, &(nonce + 1));
+        nonce
+    }
+
     #[inline]
     default fn _get_votes(&self, account: &AccountId) -> Result<Vote, VotesError> {
         let checkpoints = self._get_checkpoints(account)?;
@@ -434,4 +593,77 @@ where
             }
         }
     }
+
+    default fn _init_locker(&mut self, locker: AccountId) {
+        self.data::<Data>().locker = Some(locker);
+    }
+
+    default fn _lock(
+        &mut self,
+        account: &AccountId,
+        amount: Balance,
+        until: BlockNumber,
+    ) -> Result<(), PSP22VotesError> {
+        if amount == 0 || until <= Self::env().block_number() {
+            return Err(PSP22VotesError::InvalidLock)
+        }
+
+        if amount > self.data::<psp22::Data>()._balance_of(account) {
+            return Err(PSP22VotesError::InsufficientBalance)
+        }
+
+        let mut locks = self.data::<Data>().locks.get(account).unwrap_or_default();
+        locks.push(Lock { amount, until });
+        self.data::<Data>().locks.insert(account, &locks);
+
+        self._emit_tokens_locked(*account, amount, until);
+        Ok(())
+    }
+
+    default fn _unlock(&mut self, account: &AccountId) -> Result<(), PSP22VotesError> {
+        let old_locked_balance = self._locked_balance(account);
+        let block_number = Self::env().block_number();
+
+        let mut locks = self.data::<Data>().locks.get(account).unwrap_or_default();
+        let len = locks.len();
+        locks.retain(|lock| lock.until > block_number);
+        if locks.len() == len {
+            return Err(PSP22VotesError::NoExpiredLock)
+        }
+
+        if locks.is_empty() {
+            self.data::<Data>().locks.remove(account);
+        } else {
+            self.data::<Data>().locks.insert(account, &locks);
+        }
+
+        let new_locked_balance = self._locked_balance(account);
+        self._emit_tokens_unlocked(*account, old_locked_balance - new_locked_balance);
+        Ok(())
+    }
+
+    default fn _locked_balance(&self, account: &AccountId) -> Balance {
+        self.data::<Data>()
+            .locks
+            .get(account)
+            .unwrap_or_default()
+            .iter()
+            .map(|lock| lock.amount)
+            .max()
+            .unwrap_or_default()
+    }
+
+    default fn _before_token_transfer_locks(
+        &self,
+        from: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22VotesError> {
+        if let Some(from) = from {
+            let balance = self.data::<psp22::Data>()._balance_of(from);
+            if balance.saturating_sub(*amount) < self._locked_balance(from) {
+                return Err(PSP22VotesError::LockedBalance)
+            }
+        }
+        Ok(())
+    }
 }
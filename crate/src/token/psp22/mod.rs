@@ -1,4 +1,7 @@
 pub mod extensions {
+    /// PSP22 permit extension.
+    #[cfg(feature = "psp22_permit")]
+    pub mod psp22_permit;
     /// PSP22 votes extension.
     pub mod psp22_votes;
 }
@@ -14,6 +14,16 @@ pub enum CountingError {
     VoteAlreadyCast,
     /// Returns when cast an invalid vote type
     InvalidVoteType,
+    /// Returns when the params of a vote cannot be decoded
+    InvalidParams,
+    /// Returns when the votes cast by an account exceed its weight
+    VoteWeightExceeded,
+    /// Returns when a vote does not cast any votes
+    EmptyVote,
+    /// Returns when the votes of a proposal overflow
+    VotesOverflow,
+    /// Returns when the token fails to lock the weight of a vote
+    TokenLockFailed,
 }
 
 impl From<GovernorError> for CountingError {
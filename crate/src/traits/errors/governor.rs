@@ -1,6 +1,14 @@
-use ink::LangError;
+use ink::{
+    prelude::vec::Vec,
+    LangError,
+};
 use openbrush::traits::String;
 
+use super::{
+    CountingError,
+    TimelockControllerError,
+};
+
 /// Governor module error type.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -23,12 +31,57 @@ pub enum GovernorError {
     ProposalNotSuccessful,
     /// Returned if the call is reverted without message
     CallRevertedWithoutMessage,
+    /// Returned if the call is reverted, with the SCALE encoded error returned by the callee
+    CallReverted(Vec<u8>),
     /// Returned if the proposal is not active
     ProposalNotActive,
     /// Returned if the function was not passed through governance proposal
     OnlyGovernance,
+    /// Returned if the caller is neither the proposer of the proposal nor the guardian
+    OnlyProposerOrGuardian,
+    /// Returned if the proposal is not pending
+    ProposalNotPending,
+    /// Returned if the proposal cannot be queued because no queueing mechanism is configured
+    QueueNotSupported,
+    /// Returned if the quorum numerator is greater than the quorum denominator
+    InvalidQuorumFraction,
+    /// Returned if the options of a proposal are fewer than two or more than the possible support
+    /// values
+    InvalidProposalOptions,
+    /// Returned if the success threshold is lower than the simple majority or greater than one
+    InvalidSuccessThreshold,
+    /// Returned if the veto threshold is greater than one
+    InvalidVetoThreshold,
+    /// Returned if the value transferred is lower than the deposit amount
+    InsufficientDeposit,
+    /// Returned if the proposal has no deposit to release
+    NoDeposit,
+    /// Returned if the voting of the proposal is not over
+    ProposalNotFinished,
+    /// Returned if the transfer of a deposit fails
+    DepositTransferFailed,
+    /// Returned if the votes must be committed and then revealed
+    VoteMustBeCommitted,
+    /// Returned if the account has no commitment on the proposal
+    NoCommitment,
+    /// Returned if the revealed vote does not match the commitment
+    InvalidReveal,
+    /// Returned if the proposal is not in its reveal window
+    ProposalNotRevealing,
+    /// Returned if the proposer is not allowed to create optimistic proposals
+    NotOptimisticProposer,
+    /// Returned if the signature does not match the signer
+    InvalidSignature,
     /// Returned if the votes for that account was not found.
     NoVotes,
+    /// Error from TimelockController
+    TimelockControllerError(TimelockControllerError),
+    /// Returned if the value transferred by the transactions of a proposal overflows
+    TransferredValueOverflow,
+    /// Returned if the votes cannot be changed or retracted
+    VoteChangingDisabled,
+    /// Returned if the account has no vote on the proposal
+    VoteNotCast,
 }
 
 impl From<LangError> for GovernorError {
@@ -36,3 +89,33 @@ impl From<LangError> for GovernorError {
         GovernorError::Custom(String::from("LE lang err"))
     }
 }
+
+impl From<CountingError> for GovernorError {
+    fn from(counting: CountingError) -> Self {
+        match counting {
+            CountingError::Custom(message) => GovernorError::Custom(message),
+            CountingError::GovernorError(governor) => governor,
+            CountingError::VoteAlreadyCast => {
+                GovernorError::Custom(String::from("C::VoteAlreadyCast"))
+            }
+            CountingError::InvalidVoteType => {
+                GovernorError::Custom(String::from("C::InvalidVoteType"))
+            }
+            CountingError::InvalidParams => {
+                GovernorError::Custom(String::from("C::InvalidParams"))
+            }
+            CountingError::VoteWeightExceeded => {
+                GovernorError::Custom(String::from("C::VoteWeightExceeded"))
+            }
+            CountingError::EmptyVote => {
+                GovernorError::Custom(String::from("C::EmptyVote"))
+            }
+            CountingError::VotesOverflow => {
+                GovernorError::Custom(String::from("C::VotesOverflow"))
+            }
+            CountingError::TokenLockFailed => {
+                GovernorError::Custom(String::from("C::TokenLockFailed"))
+            }
+        }
+    }
+}
@@ -1,13 +1,17 @@
 mod counting;
 mod counting_simple;
 mod governor;
+mod psp22_permit;
 mod psp22_votes;
+mod timelock_controller;
 mod votes;
 mod voting_group;
 
 pub use counting::CountingError;
 pub use counting_simple::CountingSimpleError;
 pub use governor::GovernorError;
+pub use psp22_permit::PSP22PermitError;
 pub use psp22_votes::PSP22VotesError;
+pub use timelock_controller::TimelockControllerError;
 pub use votes::VotesError;
 pub use voting_group::VotingGroupError;
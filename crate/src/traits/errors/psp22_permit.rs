@@ -0,0 +1,24 @@
+use openbrush::{
+    contracts::psp22::PSP22Error,
+    traits::String,
+};
+
+/// PSP22Permit module error type.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22PermitError {
+    /// Custom error type for cases if writer of traits added own restrictions
+    Custom(String),
+    /// Errors from PSP22
+    PSP22(PSP22Error),
+    /// Returns when the permit is used after its deadline
+    SignatureExpired,
+    /// Returns when the signature does not match the owner
+    InvalidSignature,
+}
+
+impl From<PSP22Error> for PSP22PermitError {
+    fn from(error: PSP22Error) -> Self {
+        PSP22PermitError::PSP22(error)
+    }
+}
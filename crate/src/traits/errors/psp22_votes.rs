@@ -17,6 +17,16 @@ pub enum PSP22VotesError {
     PSP22(PSP22Error),
     /// Returns when a convertion fail
     ConvertionError { from: String, to: String },
+    /// Returns when the caller is not the locker of the token
+    NotLocker,
+    /// Returns when a lock has no amount or it is already expired
+    InvalidLock,
+    /// Returns when an account has no expired lock to remove
+    NoExpiredLock,
+    /// Returns when a transfer exceeds the balance that is not locked
+    LockedBalance,
+    /// Returns when a lock exceeds the balance of the account
+    InsufficientBalance,
 }
 
 impl From<VotesError> for PSP22VotesError {
@@ -40,6 +50,15 @@ impl From<VotesError> for PSP22VotesError {
             VotesError::BalanceToVoteErr => {
                 PSP22VotesError::Custom(String::from("Votes::BalanceToVoteErr"))
             }
+            VotesError::SignatureExpired => {
+                PSP22VotesError::Custom(String::from("Votes::SignatureExpired"))
+            }
+            VotesError::InvalidNonce => {
+                PSP22VotesError::Custom(String::from("Votes::InvalidNonce"))
+            }
+            VotesError::InvalidSignature => {
+                PSP22VotesError::Custom(String::from("Votes::InvalidSignature"))
+            }
             VotesError::Custom(string) => PSP22VotesError::Custom(string),
         }
     }
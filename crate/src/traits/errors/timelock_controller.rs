@@ -0,0 +1,36 @@
+use openbrush::{
+    contracts::traits::errors::AccessControlError,
+    traits::String,
+};
+
+/// TimelockController module error type.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum TimelockControllerError {
+    /// Custom error type for cases if writer of traits added own restrictions
+    Custom(String),
+    /// Error from AccessControl
+    AccessControlError(AccessControlError),
+    /// Returned if the delay is lower than the minimum delay
+    InsufficientDelay,
+    /// Returned if the operation is already scheduled
+    OperationAlreadyScheduled,
+    /// Returned if the operation is not pending and so it cannot be canceled
+    OperationCannotBeCanceled,
+    /// Returned if the operation is not ready to be executed
+    OperationIsNotReady,
+    /// Returned if the predecessor operation was not executed yet
+    MissingDependency,
+    /// Returned if the call of the underlying transaction reverted
+    UnderlyingTransactionReverted,
+    /// Returned if the caller is not the timelock itself
+    CallerMustBeTimeLock,
+    /// Returned if the timestamp of the operation overflows
+    TimestampOverflow,
+}
+
+impl From<AccessControlError> for TimelockControllerError {
+    fn from(access: AccessControlError) -> Self {
+        TimelockControllerError::AccessControlError(access)
+    }
+}
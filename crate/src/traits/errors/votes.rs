@@ -18,4 +18,10 @@ pub enum VotesError {
     MovePowerAmountError,
     /// Reuturns when a conversion from Balance to Vote failed
     BalanceToVoteErr,
+    /// Returns when the signature is used after its expiry
+    SignatureExpired,
+    /// Returns when the nonce of the signature is not the current nonce of the signer
+    InvalidNonce,
+    /// Returns when the signature does not match the signer
+    InvalidSignature,
 }
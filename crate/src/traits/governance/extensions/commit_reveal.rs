@@ -0,0 +1,84 @@
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+    Hash,
+};
+
+use crate::traits::{
+    errors::GovernorError,
+    governance::ProposalId,
+};
+
+/// Extension of Governor for secret ballots through commit-reveal.
+#[openbrush::wrapper]
+pub type GovernorCommitRevealRef = dyn GovernorCommitReveal;
+
+/// Trait definition of extension of Governor for secret ballots. During the voting period the
+/// voters commit the hash of their vote, so the votes are not public until the voting period is
+/// over. A reveal window follows the deadline of the proposal: in it the voters reveal their
+/// votes, which are counted only if they match the commitment.
+///
+/// The commitments that are not revealed are not counted. The proposal is in the Revealing state
+/// during the reveal window and its outcome is final once the window is over.
+#[openbrush::trait_definition]
+pub trait GovernorCommitReveal {
+    /// Returns the number of blocks after the deadline of a proposal in which the votes can be
+    /// revealed.
+    #[ink(message)]
+    fn reveal_period(&self) -> BlockNumber;
+
+    /// Update the reveal period. This operation can only be performed through a governance
+    /// proposal.
+    ///
+    /// Emits a RevealPeriodSet event.
+    #[ink(message)]
+    fn set_reveal_period(
+        &mut self,
+        new_reveal_period: BlockNumber,
+    ) -> Result<(), GovernorError>;
+
+    /// Returns the commitment of an account on a proposal that has not been revealed yet.
+    #[ink(message)]
+    fn vote_commitment(
+        &self,
+        proposal_id: ProposalId,
+        account: AccountId,
+    ) -> Option<Hash>;
+
+    /// Returns the commitment of a vote: the blake2x256 hash of the SCALE encoded tuple
+    /// (`proposal_id`, `voter`, `support`, `salt`, `params`), so a commitment cannot be copied by
+    /// another account or on another proposal.
+    #[ink(message)]
+    fn hash_vote(
+        &self,
+        proposal_id: ProposalId,
+        voter: AccountId,
+        support: u8,
+        salt: Hash,
+        params: Vec<u8>,
+    ) -> Hash;
+
+    /// Commit a vote on an active proposal, a new commitment replaces the previous one.
+    ///
+    /// Emits a VoteCommitted event.
+    #[ink(message)]
+    fn commit_vote(
+        &mut self,
+        proposal_id: ProposalId,
+        commitment: Hash,
+    ) -> Result<(), GovernorError>;
+
+    /// Reveal the vote committed by the caller during the reveal window of the proposal and count
+    /// it with the weight of the caller at the proposal snapshot.
+    ///
+    /// Emits a VoteCast event or VoteCastWithParams event depending on the length of params.
+    #[ink(message)]
+    fn reveal_vote(
+        &mut self,
+        proposal_id: ProposalId,
+        support: u8,
+        salt: Hash,
+        params: Vec<u8>,
+    ) -> Result<u64, GovernorError>;
+}
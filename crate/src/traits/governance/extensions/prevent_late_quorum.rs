@@ -0,0 +1,32 @@
+use openbrush::traits::BlockNumber;
+
+use crate::traits::errors::GovernorError;
+
+/// Extension of Governor that ensures there is a minimum voting period after quorum is reached.
+#[openbrush::wrapper]
+pub type GovernorPreventLateQuorumRef = dyn GovernorPreventLateQuorum;
+
+/// Trait definition of extension of Governor that ensures there is a minimum voting period after
+/// quorum is reached. This prevents a large voter from swaying a vote and triggering quorum at the
+/// last minute, by ensuring there is always time for other voters to react and try to oppose the
+/// decision.
+///
+/// If a vote causes quorum to be reached, the proposal's voting period may be extended so that it
+/// does not end before at least a given number of blocks have passed (the "vote extension").
+#[openbrush::trait_definition]
+pub trait GovernorPreventLateQuorum {
+    /// Returns the current value of the vote extension parameter: the number of blocks that are
+    /// required to pass from the time a proposal reaches quorum until its voting period ends.
+    #[ink(message)]
+    fn late_quorum_vote_extension(&self) -> BlockNumber;
+
+    /// Update the vote extension parameter. This operation can only be performed through a
+    /// governance proposal.
+    ///
+    /// Emits a LateQuorumVoteExtensionSet event.
+    #[ink(message)]
+    fn set_late_quorum_vote_extension(
+        &mut self,
+        new_vote_extension: BlockNumber,
+    ) -> Result<(), GovernorError>;
+}
@@ -0,0 +1,77 @@
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    String,
+};
+
+use crate::traits::{
+    errors::GovernorError,
+    governance::{
+        Proposal,
+        ProposalId,
+    },
+};
+
+/// A Deposit is the value transferred by the proposer when a proposal is created.
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Deposit {
+    /// The account that made the deposit.
+    pub depositor: AccountId,
+    /// The value deposited.
+    pub amount: Balance,
+}
+
+/// Extension of Governor that requires a deposit to create a proposal.
+#[openbrush::wrapper]
+pub type GovernorProposalDepositRef = dyn GovernorProposalDeposit;
+
+/// Trait definition of extension of Governor that requires the proposer to transfer a deposit
+/// when a proposal is created. Once the voting of the proposal is over the deposit is refunded to
+/// the proposer, unless the proposal was vetoed: in this case the deposit is slashed and it is
+/// kept by the governor.
+///
+/// Note: The veto is provided by the counting module (see `governor_counting_veto`).
+/// Note: The deposit is enforced only if the user overrides the `_after_propose` method of
+/// governor::Internal to call `_store_deposit` (see `governor_proposal_deposit::Internal`).
+#[openbrush::trait_definition]
+pub trait GovernorProposalDeposit {
+    /// Returns the minimum value that must be transferred to create a proposal.
+    #[ink(message)]
+    fn deposit_amount(&self) -> Balance;
+
+    /// Update the minimum value that must be transferred to create a proposal. This operation
+    /// can only be performed through a governance proposal.
+    ///
+    /// Emits a DepositAmountSet event.
+    #[ink(message)]
+    fn set_deposit_amount(
+        &mut self,
+        new_deposit_amount: Balance,
+    ) -> Result<(), GovernorError>;
+
+    /// Returns the deposit of a proposal that has not been released yet.
+    #[ink(message)]
+    fn proposal_deposit(&self, proposal_id: ProposalId) -> Option<Deposit>;
+
+    /// Create a new proposal, the value transferred is the deposit of the proposal and it must be
+    /// at least the deposit amount.
+    ///
+    /// Emits a ProposalCreated event.
+    #[ink(message, payable)]
+    fn propose_with_deposit(
+        &mut self,
+        proposal: Proposal,
+        description: String,
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Release the deposit of a proposal whose voting is over: refund it to the depositor, or
+    /// slash it if the proposal was vetoed. Anyone can release a deposit.
+    ///
+    /// Emits a DepositRefunded or a DepositSlashed event.
+    #[ink(message)]
+    fn release_deposit(&mut self, proposal_id: ProposalId) -> Result<(), GovernorError>;
+}
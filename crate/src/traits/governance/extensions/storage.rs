@@ -0,0 +1,72 @@
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Hash,
+};
+
+use crate::traits::{
+    errors::GovernorError,
+    governance::{
+        Proposal,
+        ProposalId,
+    },
+};
+
+/// The details of a proposal stored on-chain
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ProposalDetails {
+    /// The account that created the proposal
+    pub proposer: AccountId,
+    /// The transactions of the proposal
+    pub proposal: Proposal,
+    /// The hash of the description of the proposal
+    pub description_hash: Hash,
+}
+
+/// Extension of Governor that stores the proposals on-chain.
+#[openbrush::wrapper]
+pub type GovernorStorageRef = dyn GovernorStorage;
+
+/// Trait definition of extension of Governor that keeps an ordered index of the proposals and
+/// stores their details, so that they can be enumerated without an indexer and queued, executed or
+/// canceled knowing only their id.
+#[openbrush::trait_definition]
+pub trait GovernorStorage {
+    /// Returns the number of stored proposals.
+    #[ink(message)]
+    fn proposal_count(&self) -> u32;
+
+    /// Returns the details of a proposal, None if the proposal was not found.
+    #[ink(message)]
+    fn proposal_details(&self, proposal_id: ProposalId) -> Option<ProposalDetails>;
+
+    /// Returns at most `limit` proposals with their details, in order of creation, starting from
+    /// the `offset` index.
+    #[ink(message)]
+    fn proposals(&self, offset: u32, limit: u32) -> Vec<(ProposalId, ProposalDetails)>;
+
+    /// Version of `queue` with only `proposal_id` as an argument.
+    #[ink(message)]
+    fn queue_by_id(
+        &mut self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Version of `execute` with only `proposal_id` as an argument.
+    #[ink(message, payable)]
+    fn execute_by_id(
+        &mut self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Version of `cancel` with only `proposal_id` as an argument.
+    #[ink(message)]
+    fn cancel_by_id(
+        &mut self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalId, GovernorError>;
+}
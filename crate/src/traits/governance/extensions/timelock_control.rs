@@ -0,0 +1,55 @@
+use openbrush::traits::{
+    AccountId,
+    Hash,
+    Timestamp,
+};
+
+use crate::traits::{
+    errors::GovernorError,
+    governance::{
+        Proposal,
+        ProposalId,
+    },
+};
+
+/// Extension of Governor that binds the execution process to an instance of TimelockController.
+#[openbrush::wrapper]
+pub type GovernorTimelockControlRef = dyn GovernorTimelockControl;
+
+/// Trait definition of extension of Governor that binds the execution process to an instance of
+/// TimelockController. This adds a delay, enforced by the TimelockController, to all successful
+/// proposals (in addition to the voting duration).
+///
+/// Note: The Governor needs the proposer (and ideally the executor) roles on the TimelockController
+/// for the execution to work, and the TimelockController becomes the executor of the Governor, so
+/// any asset or permission must be held by the TimelockController and not by the Governor.
+#[openbrush::trait_definition]
+pub trait GovernorTimelockControl {
+    /// Returns the address of the timelock.
+    #[ink(message)]
+    fn timelock(&self) -> AccountId;
+
+    /// Returns the timestamp at which a queued proposal becomes executable, None if the proposal
+    /// was not queued.
+    #[ink(message)]
+    fn proposal_eta(&self, proposal_id: ProposalId) -> Option<Timestamp>;
+
+    /// Queue a successful proposal to the timelock.
+    ///
+    /// Emits a ProposalQueued event.
+    #[ink(message)]
+    fn queue(
+        &mut self,
+        proposal: Proposal,
+        description_hash: Hash,
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Update the underlying timelock instance. This operation can only be performed through a
+    /// governance proposal.
+    ///
+    /// Emits a TimelockChange event.
+    ///
+    /// Note: It is not recommended to change the timelock while there are queued proposals.
+    #[ink(message)]
+    fn update_timelock(&mut self, new_timelock: AccountId) -> Result<(), GovernorError>;
+}
@@ -0,0 +1,42 @@
+use openbrush::traits::BlockNumber;
+
+use crate::traits::errors::GovernorError;
+
+/// Extension of Governor for a quorum expressed as a fraction of the total supply.
+#[openbrush::wrapper]
+pub type GovernorVotesQuorumFractionRef = dyn GovernorVotesQuorumFraction;
+
+/// Trait definition of extension of Governor for a quorum expressed as a fraction of the total
+/// supply of votes at the proposal snapshot.
+///
+/// The quorum numerator is checkpointed, so that updating it does not change the outcome of past
+/// proposals.
+#[openbrush::trait_definition]
+pub trait GovernorVotesQuorumFraction {
+    /// Returns the current quorum numerator.
+    #[ink(message)]
+    fn quorum_numerator(&self) -> u64;
+
+    /// Returns the quorum numerator at a specific block number.
+    #[ink(message)]
+    fn quorum_numerator_at(&self, block_number: BlockNumber) -> u64;
+
+    /// Returns the quorum denominator.
+    #[ink(message)]
+    fn quorum_denominator(&self) -> u64;
+
+    /// Returns the quorum for a block number, in terms of number of votes:
+    /// `supply * numerator / denominator`.
+    #[ink(message)]
+    fn quorum_at(&self, block_number: BlockNumber) -> Result<u64, GovernorError>;
+
+    /// Update the quorum numerator. This operation can only be performed through a governance
+    /// proposal.
+    ///
+    /// Emits a QuorumNumeratorUpdated event.
+    #[ink(message)]
+    fn update_quorum_numerator(
+        &mut self,
+        new_quorum_numerator: u64,
+    ) -> Result<(), GovernorError>;
+}
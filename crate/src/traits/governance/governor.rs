@@ -1,4 +1,7 @@
-pub use crate::traits::errors::GovernorError;
+pub use crate::traits::{
+    errors::GovernorError,
+    signature::Signature,
+};
 use ink::prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
@@ -21,15 +24,21 @@ pub enum ProposalState {
     Queued,
     Expired,
     Executed,
+    /// The voting period is over and the committed votes can be revealed, only with a
+    /// commit-reveal extension.
+    Revealing,
 }
 
 /// Hash type which identifies an unique id for a proposal
 pub type ProposalId = Hash;
 
-/// A Proposal is what can be proposed
+/// A Transaction is a call that can be executed
 #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
-#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-pub struct Proposal {
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Transaction {
     /// The `AccountId` of the contract that is called in this transaction.
     pub callee: AccountId,
     /// The selector bytes that identifies the function of the callee that should be called.
@@ -40,7 +49,7 @@ pub struct Proposal {
     pub transferred_value: Balance,
 }
 
-impl Default for Proposal {
+impl Default for Transaction {
     fn default() -> Self {
         Self {
             callee: ZERO_ADDRESS.into(),
@@ -51,6 +60,104 @@ impl Default for Proposal {
     }
 }
 
+/// A Proposal is what can be proposed
+#[derive(Debug, Default, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Proposal {
+    /// The ordered list of transactions executed atomically by the proposal.
+    pub transactions: Vec<Transaction>,
+}
+
+impl Proposal {
+    /// Returns the amount of chain balance that is transferred by all the transactions.
+    pub fn transferred_value(&self) -> Result<Balance, GovernorError> {
+        self.transactions
+            .iter()
+            .try_fold(0, |total: Balance, transaction| {
+                total.checked_add(transaction.transferred_value)
+            })
+            .ok_or(GovernorError::TransferredValueOverflow)
+    }
+}
+
+/// A Ballot is the vote signed off-chain by a voter to be cast by a relayer
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Ballot {
+    /// The id of the proposal.
+    pub proposal_id: ProposalId,
+    /// The vote type casted.
+    pub support: u8,
+    /// Reason of the vote.
+    pub reason: String,
+    /// Additional encoded parameters of the vote.
+    pub params: Vec<u8>,
+    /// The current nonce of the voter, it prevents the replay of the signature.
+    pub nonce: u64,
+}
+
+/// A wrapper that allows us to encode a blob of bytes.
+///
+/// We use this to pass the set of untyped (bytes) parameters to the `CallBuilder`.
+pub struct CallInput<'a>(pub &'a [u8]);
+
+impl<'a> scale::Encode for CallInput<'a> {
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.0);
+    }
+}
+
+/// A wrapper that allows us to decode the raw output of a call.
+///
+/// We use this to get back the output of the `CallBuilder` regardless of the return type of the
+/// called function.
+pub struct CallOutput(pub Vec<u8>);
+
+impl scale::Decode for CallOutput {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let len = input.remaining_len()?.unwrap_or_default();
+        let mut output = Vec::with_capacity(len);
+        output.resize(len, 0);
+        input.read(&mut output)?;
+        Ok(CallOutput(output))
+    }
+}
+
+impl CallOutput {
+    /// Decode the output of a call to a function that returns `Result<T, E>`, an `Err` returned
+    /// by the function is returned as `CallReverted` with the SCALE encoded error.
+    pub fn decode_result<T, E>(&self) -> Result<T, GovernorError>
+    where
+        T: scale::Decode,
+        E: scale::Encode + scale::Decode,
+    {
+        match <Result<T, E> as scale::DecodeAll>::decode_all(&mut &self.0[..]) {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(error)) => {
+                Err(GovernorError::CallReverted(scale::Encode::encode(&error)))
+            }
+            Err(_) => Err(GovernorError::CallRevertedWithoutMessage),
+        }
+    }
+
+    /// Check the output of a call to a function that returns `()` or `Result<T, E>` without
+    /// knowing `T` and `E`, an `Err` returned by the function is returned as `CallReverted` with
+    /// the SCALE encoded error.
+    ///
+    /// Note: Any other output is rejected as `CallRevertedWithoutMessage`, since it can't be told
+    /// apart from an `Err` (e.g. a function that returns `true`).
+    pub fn check_result(&self) -> Result<(), GovernorError> {
+        match self.0.split_first() {
+            None | Some((0, _)) => Ok(()),
+            Some((1, error)) => Err(GovernorError::CallReverted(error.to_vec())),
+            Some(_) => Err(GovernorError::CallRevertedWithoutMessage),
+        }
+    }
+}
+
 /// Wrapper to simplify cross-contract call
 #[openbrush::wrapper]
 pub type GovernorRef = dyn Governor;
@@ -83,6 +190,8 @@ pub trait Governor {
     ) -> Result<BlockNumber, GovernorError>;
 
     /// A description of the possible support values for castVote and the way these votes are counted, meant to be consumed by UIs to show correct vote options and interpret the results.
+    ///
+    /// Note: The description is provided by the counting module.
     #[ink(message)]
     fn counting_mode(&self) -> String;
 
@@ -114,6 +223,9 @@ pub trait Governor {
 
     /// Execute a successful proposal. This requires the quorum to be reached, the vote to be successful, and the deadline to be reached.
     ///
+    /// The transactions of the proposal are executed in order and atomically: if any of them fails the
+    /// whole execution is reverted.
+    ///
     /// Emits a ProposalExecuted event.
     ///
     /// Note: some module can modify the requirements for execution, for example by adding an
@@ -125,6 +237,30 @@ pub trait Governor {
         description_hash: Hash,
     ) -> Result<ProposalId, GovernorError>;
 
+    /// Cancel a proposal. The proposer can cancel the proposal only while it is pending, the
+    /// guardian can cancel it at any time before its execution.
+    ///
+    /// Emits a ProposalCanceled event.
+    #[ink(message)]
+    fn cancel(
+        &mut self,
+        proposal: Proposal,
+        description_hash: Hash,
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Returns the account that can cancel any proposal before its execution, if any.
+    #[ink(message)]
+    fn guardian(&self) -> Option<AccountId>;
+
+    /// Update the guardian. This operation can only be performed through a governance proposal.
+    ///
+    /// Emits a GuardianSet event.
+    #[ink(message)]
+    fn set_guardian(
+        &mut self,
+        new_guardian: Option<AccountId>,
+    ) -> Result<(), GovernorError>;
+
     /// Returns the voting power of an account at a specific blockNumber.
     #[ink(message)]
     fn get_votes(
@@ -181,10 +317,52 @@ pub trait Governor {
         params: Vec<u8>,
     ) -> Result<u64, GovernorError>;
 
+    /// Returns the current nonce of an account. The nonce must be included in every ballot signed
+    /// by the account and it is incremented every time a signature is used.
+    #[ink(message)]
+    fn nonces(&self, account: AccountId) -> u64;
+
+    /// Cast a vote using the voter's signature of the ballot, so that the vote can be relayed by
+    /// any account.
+    ///
+    /// Emits a VoteCast event.
+    ///
+    /// Returns the weight of the vote
+    #[ink(message)]
+    fn cast_vote_by_sig(
+        &mut self,
+        proposal_id: ProposalId,
+        support: u8,
+        voter: AccountId,
+        signature: Signature,
+    ) -> Result<u64, GovernorError>;
+
+    /// Cast a vote with a reason and additional encoded params using the voter's signature of the
+    /// ballot, so that the vote can be relayed by any account.
+    ///
+    /// Emits a VoteCast event or VoteCastWithParams event depending on the length of params.
+    ///
+    /// Returns the weight of the vote
+    #[ink(message)]
+    fn cast_vote_with_reason_and_params_by_sig(
+        &mut self,
+        proposal_id: ProposalId,
+        support: u8,
+        voter: AccountId,
+        reason: String,
+        params: Vec<u8>,
+        signature: Signature,
+    ) -> Result<u64, GovernorError>;
+
     /// Relays a transaction or function call to an arbitrary target. In cases where the governance
     /// executor is some contract other than the governor itself, like when using a timelock, this
     /// function can be invoked in a governance proposal to recover tokens that was sent to the
     /// governor contract by mistake. Note that if the executor is simply the governor itself, use of relay is redundant.
+    ///
+    /// This operation can only be performed through a governance proposal.
+    ///
+    /// Note: The output of the called function is checked by `_check_call_output`, an `Err` returned
+    /// by the callee is propagated as `CallReverted`.
     #[ink(message)]
-    fn relay(&mut self, proposal: Proposal) -> Result<(), GovernorError>;
+    fn relay(&mut self, transaction: Transaction) -> Result<(), GovernorError>;
 }
@@ -6,20 +6,45 @@ pub use governor::*;
 pub mod modules {
     /// Trait definition that a "counter" sub-module must implement
     pub mod counter;
+    /// Trait definition of counting conviction "counter" sub-module
+    pub mod counting_conviction;
+    /// Trait definition of counting approval "counter" sub-module
+    pub mod counting_approval;
+    /// Trait definition of counting fractional "counter" sub-module
+    pub mod counting_fractional;
+    /// Trait definition of counting multiple choice "counter" sub-module
+    pub mod counting_multiple_choice;
+    /// Trait definition of counting optimistic "counter" sub-module
+    pub mod counting_optimistic;
+    /// Trait definition of counting quadratic "counter" sub-module
+    pub mod counting_quadratic;
+    /// Trait definition of counting ranked choice "counter" sub-module
+    pub mod counting_ranked_choice;
     /// Trait definition of counting simple "counter" sub-module
     pub mod counting_simple;
+    /// Trait definition of counting veto "counter" sub-module
+    pub mod counting_veto;
     /// Trait definition that a "voter" sub-module must implement
     pub mod voter;
+    /// Trait definition of governor votes "voter" sub-module
+    pub mod votes;
     /// Trait definition of voting group "voter" sub-module
     pub mod voting_group;
 }
 
 /// Traits definition of extensions of governor base contracts.
 pub mod extensions {
+    pub mod commit_reveal;
+    pub mod prevent_late_quorum;
+    pub mod proposal_deposit;
     pub mod settings;
+    pub mod storage;
+    pub mod timelock_control;
+    pub mod votes_quorum_fraction;
 }
 
 /// Traits definition of utils to extend governor base contracts.
 pub mod utils {
+    pub mod timelock_controller;
     pub mod votes;
 }
@@ -1,18 +1,68 @@
-use openbrush::traits::AccountId;
+use openbrush::traits::{
+    AccountId,
+    String,
+};
 
 use crate::traits::{
     errors::CountingError,
-    governance::ProposalId,
+    governance::{
+        Proposal,
+        ProposalId,
+    },
 };
 
 /// Trait that a "counter" sub-modules must implement
 pub trait Counter {
-    /// Amount of votes already cast passes the threshold limit.
-    fn _quorum_reached(&self, proposal_id: &ProposalId) -> Result<bool, CountingError>;
+    /// A description of the possible support values and the way the votes are counted, meant to
+    /// be consumed by UIs.
+    ///
+    /// Note: By default the counting modules do not describe their counting mode.
+    fn _counting_mode(&self) -> String {
+        String::new()
+    }
+
+    /// Hook after a proposal is created by proposer, an error reverts the creation of the
+    /// proposal.
+    ///
+    /// Note: By default the counting modules do not keep the proposals.
+    fn _proposal_created(
+        &mut self,
+        _proposer: &AccountId,
+        _proposal_id: &ProposalId,
+        _proposal: &Proposal,
+    ) -> Result<(), CountingError> {
+        Ok(())
+    }
+
+    /// Amount of votes already cast passes the `quorum` provided by the governor for the proposal
+    /// snapshot, `None` if the voting module does not provide a quorum.
+    fn _quorum_reached(
+        &self,
+        proposal_id: &ProposalId,
+        quorum: Option<u64>,
+    ) -> Result<bool, CountingError>;
 
     /// Is the proposal successful or not.
     fn _vote_succeeded(&self, proposal_id: &ProposalId) -> Result<bool, CountingError>;
 
+    /// Is the proposal vetoed or not, a vetoed proposal is defeated and its deposit is slashed.
+    ///
+    /// Note: By default the counting modules do not support a veto.
+    fn _vote_vetoed(&self, _proposal_id: &ProposalId) -> Result<bool, CountingError> {
+        Ok(false)
+    }
+
+    /// Returns the support and the weight of the vote cast by account on proposalId.
+    ///
+    /// Note: By default the counting modules do not keep the votes of the accounts.
+    fn _vote_of(
+        &self,
+        _proposal_id: &ProposalId,
+        _account: &AccountId,
+    ) -> Option<(u8, u64)> {
+        None
+    }
+
     /// Register a vote for proposalId by account with a given support, voting weight and voting params.
     ///
     /// Note: Support is generic and can represent various things depending on the voting system used.
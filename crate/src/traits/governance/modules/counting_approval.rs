@@ -0,0 +1,93 @@
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+    String,
+};
+
+use crate::traits::{
+    errors::{
+        CountingSimpleError,
+        GovernorError,
+    },
+    governance::{
+        Proposal,
+        ProposalId,
+    },
+};
+
+/// An ApprovalVotes is the rapresentation of the votes a proposal with approval voting may have.
+#[derive(Debug, Default, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ApprovalVotes {
+    /// The votes that every option received, in the order of the options.
+    pub option_votes: Vec<u64>,
+    /// The weight of all the votes cast, counted once per voter.
+    pub total_votes: u64,
+}
+
+#[openbrush::wrapper]
+pub type CountingApprovalRef = dyn CountingApproval;
+
+/// Trait definition of counting approval module.
+///
+/// Every proposal declares a list of options and the number of options that win it. The params
+/// of a vote are the bitmap of the approved options (the bit `i % 8` of the byte `i / 8` is the
+/// option indexed by `i`), a vote without params approves only the option indexed by `support`.
+/// Every approved option receives the whole weight of the vote. The quorum is reached when the
+/// votes cast reach it, the proposal is successful if its winning options are not tied with the
+/// other options.
+#[openbrush::trait_definition]
+pub trait CountingApproval {
+    /// Create a new proposal with a list of options that can be approved and the number of
+    /// options with the most votes that win the proposal.
+    ///
+    /// Emits a ProposalCreated event.
+    ///
+    /// Note: A proposal created through `propose` has no options and it cannot be voted.
+    #[ink(message)]
+    fn propose_with_options(
+        &mut self,
+        proposal: Proposal,
+        description: String,
+        options: Vec<String>,
+        winners: u8,
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Minimum number of cast voted required for a proposal whose snapshot is at a specific
+    /// blockNumber to be successful.
+    ///
+    /// Note: The quorum is provided by the governor (by default from the voting module).
+    #[ink(message)]
+    fn quorum(&self, block_number: BlockNumber) -> Result<u64, GovernorError>;
+
+    /// Returns whether account has cast a vote on proposalId.
+    #[ink(message)]
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
+
+    /// Returns the options of a proposal, the index of an option is its bit in the bitmap of a
+    /// vote.
+    #[ink(message)]
+    fn proposal_options(&self, proposal_id: ProposalId) -> Vec<String>;
+
+    /// Returns the number of options that win a proposal.
+    #[ink(message)]
+    fn number_of_winners(&self, proposal_id: ProposalId) -> u8;
+
+    /// Returns the votes that a proposal has already received
+    #[ink(message)]
+    fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<ApprovalVotes, CountingSimpleError>;
+
+    /// Returns the indexes of the winning options of a proposal ordered by votes, empty if the
+    /// last winning option is tied with an option that does not win.
+    ///
+    /// Note: The result is final only once the voting period of the proposal is over.
+    #[ink(message)]
+    fn winning_options(&self, proposal_id: ProposalId) -> Vec<u8>;
+}
@@ -0,0 +1,127 @@
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+};
+
+pub use crate::traits::governance::modules::counting_simple::{
+    ProposalVote,
+    VoteType,
+};
+
+use crate::traits::{
+    errors::{
+        CountingError,
+        CountingSimpleError,
+    },
+    governance::ProposalId,
+};
+
+/// A Conviction is the multiplier that a voter applies to its voting weight, in exchange the
+/// weight is locked in the token for a number of lock periods that doubles at every level.
+#[derive(Debug, Default, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+#[repr(u8)]
+pub enum Conviction {
+    /// 0.1x votes, unlocked.
+    #[default]
+    None,
+    /// 1x votes, locked for 1 period.
+    Locked1x,
+    /// 2x votes, locked for 2 periods.
+    Locked2x,
+    /// 3x votes, locked for 4 periods.
+    Locked3x,
+    /// 4x votes, locked for 8 periods.
+    Locked4x,
+    /// 5x votes, locked for 16 periods.
+    Locked5x,
+    /// 6x votes, locked for 32 periods.
+    Locked6x,
+}
+
+impl TryFrom<u8> for Conviction {
+    type Error = CountingError;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Conviction::None),
+            1 => Ok(Conviction::Locked1x),
+            2 => Ok(Conviction::Locked2x),
+            3 => Ok(Conviction::Locked3x),
+            4 => Ok(Conviction::Locked4x),
+            5 => Ok(Conviction::Locked5x),
+            6 => Ok(Conviction::Locked6x),
+            _ => Err(CountingError::InvalidParams),
+        }
+    }
+}
+
+impl Conviction {
+    /// Returns the votes cast with `weight`, None on overflow.
+    pub fn votes(&self, weight: u64) -> Option<u64> {
+        match self {
+            Conviction::None => Some(weight / 10),
+            conviction => weight.checked_mul(*conviction as u64),
+        }
+    }
+
+    /// Returns the number of lock periods of the weight.
+    pub fn lock_periods(&self) -> u32 {
+        match self {
+            Conviction::None => 0,
+            conviction => 1 << (*conviction as u32 - 1),
+        }
+    }
+}
+
+#[openbrush::wrapper]
+pub type CountingConvictionRef = dyn CountingConviction;
+
+/// Trait definition of counting conviction module.
+///
+/// Counting simple where the voter chooses a conviction in the params of the vote (a single byte
+/// from 0 to 6, no params is 0): the weight of the vote is multiplied by the conviction and it is
+/// locked in the token (that must implement PSP22Votes and have the governor as locker) for a
+/// period that doubles at every level of conviction. The quorum counts the votes without
+/// conviction.
+///
+/// Note: A vote with conviction fails if the weight of the voter exceeds its balance of the token,
+/// so the delegated votes can be cast only without conviction.
+#[openbrush::trait_definition]
+pub trait CountingConviction {
+    /// Minimum number of cast voted required for a proposal whose snapshot is at a specific
+    /// blockNumber to be successful.
+    ///
+    /// Note: The quorum is provided by the governor (by default from the voting module).
+    #[ink(message)]
+    fn quorum(&self, block_number: BlockNumber) -> Result<u64, CountingSimpleError>;
+
+    /// Returns whether account has cast a vote on proposalId.
+    #[ink(message)]
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
+
+    /// Returns the votes, multiplied by the conviction, that a proposal has already received
+    #[ink(message)]
+    fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError>;
+
+    /// Returns the conviction of the vote of an account on a proposal.
+    #[ink(message)]
+    fn voter_conviction(
+        &self,
+        proposal_id: ProposalId,
+        account: AccountId,
+    ) -> Option<Conviction>;
+
+    /// Returns the address of the token that locks the voting weight.
+    #[ink(message)]
+    fn conviction_token(&self) -> AccountId;
+
+    /// Returns the number of blocks of a lock period.
+    #[ink(message)]
+    fn lock_period(&self) -> BlockNumber;
+}
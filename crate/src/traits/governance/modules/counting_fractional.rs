@@ -0,0 +1,48 @@
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+};
+
+pub use crate::traits::governance::modules::counting_simple::{
+    ProposalVote,
+    VoteType,
+};
+
+use crate::traits::{
+    errors::CountingSimpleError,
+    governance::ProposalId,
+};
+
+#[openbrush::wrapper]
+pub type CountingFractionalRef = dyn CountingFractional;
+
+/// Trait definition of counting fractional module.
+///
+/// A vote cast with empty params assigns the whole weight of the voter to the `support` vote type
+/// (as in counting simple). A vote cast with params assigns to each vote type the amount of votes
+/// decoded from the params as a SCALE encoded `ProposalVote`, the `support` is ignored. An account
+/// can cast fractional votes multiple times until its whole weight is used.
+#[openbrush::trait_definition]
+pub trait CountingFractional {
+    /// Minimum number of cast voted required for a proposal whose snapshot is at a specific
+    /// blockNumber to be successful.
+    ///
+    /// Note: The quorum is provided by the governor (by default from the voting module).
+    #[ink(message)]
+    fn quorum(&self, block_number: BlockNumber) -> Result<u64, CountingSimpleError>;
+
+    /// Returns whether account has cast a vote on proposalId.
+    #[ink(message)]
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
+
+    /// Returns the amount of weight that account has already used on proposalId.
+    #[ink(message)]
+    fn used_weight(&self, proposal_id: ProposalId, account: AccountId) -> u64;
+
+    /// Returns the votes that a proposal has already received
+    #[ink(message)]
+    fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError>;
+}
@@ -0,0 +1,71 @@
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+    String,
+};
+
+use crate::traits::{
+    errors::GovernorError,
+    governance::{
+        Proposal,
+        ProposalId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type CountingMultipleChoiceRef = dyn CountingMultipleChoice;
+
+/// Trait definition of counting multiple choice module.
+///
+/// Every proposal declares a list of options and the `support` of a vote is the index of the
+/// chosen option. The proposal is successful if the option with the most votes reaches the
+/// quorum and it is not tied with another option.
+#[openbrush::trait_definition]
+pub trait CountingMultipleChoice {
+    /// Create a new proposal with a list of options that can be voted.
+    ///
+    /// Emits a ProposalCreated event.
+    ///
+    /// Note: A proposal created through `propose` has no options and it cannot be voted.
+    #[ink(message)]
+    fn propose_with_options(
+        &mut self,
+        proposal: Proposal,
+        description: String,
+        options: Vec<String>,
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Minimum number of votes that the winning option of a proposal whose snapshot is at a
+    /// specific blockNumber must receive for the proposal to be successful.
+    ///
+    /// Note: The quorum is provided by the governor (by default from the voting module).
+    #[ink(message)]
+    fn quorum(&self, block_number: BlockNumber) -> Result<u64, GovernorError>;
+
+    /// Returns whether account has cast a vote on proposalId.
+    #[ink(message)]
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
+
+    /// Returns the options of a proposal, the index of an option is the support to vote it.
+    #[ink(message)]
+    fn proposal_options(&self, proposal_id: ProposalId) -> Vec<String>;
+
+    /// Returns the counting mode of a proposal, that is the `counting_mode` followed by the
+    /// options of the proposal in the order of their support, e.g.
+    /// `support=option&quorum=option&options=Alice,Bob`.
+    ///
+    /// Note: The characters `%`, `&`, `=` and `,` of an option are percent-encoded.
+    #[ink(message)]
+    fn proposal_counting_mode(&self, proposal_id: ProposalId) -> String;
+
+    /// Returns the votes that every option of a proposal has already received, in the order of
+    /// the options.
+    #[ink(message)]
+    fn option_votes(&self, proposal_id: ProposalId) -> Vec<u64>;
+
+    /// Returns the index of the option with the most votes, if it is not tied with another
+    /// option.
+    #[ink(message)]
+    fn winning_option(&self, proposal_id: ProposalId) -> Option<u8>;
+}
@@ -0,0 +1,74 @@
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+};
+
+pub use crate::traits::governance::modules::counting_simple::{
+    ProposalVote,
+    VoteType,
+};
+
+use crate::traits::{
+    errors::{
+        CountingSimpleError,
+        GovernorError,
+    },
+    governance::ProposalId,
+};
+
+#[openbrush::wrapper]
+pub type CountingOptimisticRef = dyn CountingOptimistic;
+
+/// Trait definition of counting optimistic module.
+///
+/// The inverse of counting simple: a proposal succeeds at its deadline, without a quorum, unless
+/// its against votes exceed the veto threshold. It is meant for routine operations, so only the
+/// optimistic proposers can create the proposals.
+///
+/// Note: The restriction is enforced by the `_proposal_created` hook of the module, the user can
+/// lift it only by overriding the hook.
+#[openbrush::trait_definition]
+pub trait CountingOptimistic {
+    /// Minimum number of cast voted required for a proposal whose snapshot is at a specific
+    /// blockNumber to be successful.
+    ///
+    /// Note: The quorum is provided by the governor (by default from the voting module) but it is
+    /// not required by the optimistic proposals.
+    #[ink(message)]
+    fn quorum(&self, block_number: BlockNumber) -> Result<u64, CountingSimpleError>;
+
+    /// Returns whether account has cast a vote on proposalId.
+    #[ink(message)]
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
+
+    /// Returns the votes that a proposal has already received
+    #[ink(message)]
+    fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError>;
+
+    /// Returns the number of against votes that a proposal must exceed to be vetoed.
+    #[ink(message)]
+    fn veto_threshold(&self) -> u64;
+
+    /// Update the veto threshold, it applies also to the proposals already created.
+    ///
+    /// Note: This must be done through governance.
+    #[ink(message)]
+    fn set_veto_threshold(&mut self, threshold: u64) -> Result<(), GovernorError>;
+
+    /// Returns whether account is allowed to create optimistic proposals.
+    #[ink(message)]
+    fn is_optimistic_proposer(&self, account: AccountId) -> bool;
+
+    /// Add or remove account from the optimistic proposers.
+    ///
+    /// Note: This must be done through governance.
+    #[ink(message)]
+    fn set_optimistic_proposer(
+        &mut self,
+        account: AccountId,
+        allowed: bool,
+    ) -> Result<(), GovernorError>;
+}
@@ -0,0 +1,58 @@
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+};
+
+pub use crate::traits::governance::modules::counting_simple::{
+    ProposalVote,
+    VoteType,
+};
+
+use crate::traits::{
+    errors::CountingSimpleError,
+    governance::ProposalId,
+};
+
+#[openbrush::wrapper]
+pub type CountingQuadraticRef = dyn CountingQuadratic;
+
+/// Trait definition of counting quadratic module.
+///
+/// The weight of a voter returned by the voting module is the amount of voice credits it can
+/// spend on a proposal, and casting `n` votes costs `n * n` credits. A vote cast with empty params
+/// assigns to the `support` vote type the integer square root of the weight. A vote cast with
+/// params assigns to each vote type the amount of votes decoded from the params as a SCALE
+/// encoded `ProposalVote`, the `support` is ignored. An account can cast quadratic votes multiple
+/// times until its credits are spent, the cost is always computed on the total votes of the
+/// account so splitting the votes does not make them cheaper.
+///
+/// Note: The quorum is compared to the credits spent on for and abstain votes, not to the
+/// quadratic votes.
+#[openbrush::trait_definition]
+pub trait CountingQuadratic {
+    /// Minimum number of cast voted required for a proposal whose snapshot is at a specific
+    /// blockNumber to be successful.
+    ///
+    /// Note: The quorum is provided by the governor (by default from the voting module).
+    #[ink(message)]
+    fn quorum(&self, block_number: BlockNumber) -> Result<u64, CountingSimpleError>;
+
+    /// Returns whether account has cast a vote on proposalId.
+    #[ink(message)]
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
+
+    /// Returns the votes that account has already cast on proposalId.
+    #[ink(message)]
+    fn voter_votes(&self, proposal_id: ProposalId, account: AccountId) -> ProposalVote;
+
+    /// Returns the amount of voice credits that account has already spent on proposalId.
+    #[ink(message)]
+    fn used_credits(&self, proposal_id: ProposalId, account: AccountId) -> u64;
+
+    /// Returns the votes that a proposal has already received
+    #[ink(message)]
+    fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<ProposalVote, CountingSimpleError>;
+}
@@ -0,0 +1,125 @@
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+    String,
+};
+
+use crate::traits::{
+    errors::GovernorError,
+    governance::{
+        Proposal,
+        ProposalId,
+    },
+};
+
+/// The methods available to tally the ranked ballots of a proposal
+#[derive(Debug, Default, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum RankingMode {
+    /// The option with the fewest first preferences is eliminated and its ballots are transferred
+    /// to their next preference, until an option has the majority of the votes.
+    #[default]
+    InstantRunoff,
+    /// Every option receives from each ballot a number of points equal to the number of options
+    /// ranked below it (the options that are not ranked are below all the ranked ones), the
+    /// option with the most points wins.
+    Borda,
+}
+
+/// A RankedBallot is the ordered list of preferences of a voter
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct RankedBallot {
+    /// The indexes of the options in order of preference.
+    pub preferences: Vec<u8>,
+    /// The weight of the vote.
+    pub weight: u64,
+}
+
+/// A Round is the result of a counting round of the tally
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Round {
+    /// The votes (or points with Borda) of every option, in the order of the options.
+    pub tallies: Vec<u64>,
+    /// The indexes of the options eliminated at the end of the round.
+    pub eliminated: Vec<u8>,
+}
+
+#[openbrush::wrapper]
+pub type CountingRankedChoiceRef = dyn CountingRankedChoice;
+
+/// Trait definition of counting ranked choice module.
+///
+/// Every proposal declares a list of options and the method used to tally the votes. The params
+/// of a vote are the SCALE encoded `Vec<u8>` of the indexes of the options in order of
+/// preference, a vote without params ranks only the option indexed by `support`. The quorum is
+/// reached when the votes cast reach it, the proposal is successful if the tally has a single
+/// winner.
+///
+/// The ballots are stored one by one, the instant-runoff tally reads all of them, so it is
+/// stored through `tally` once the voting period is over and an instant-runoff proposal is not
+/// successful until it is tallied. The Borda points are counted with every vote.
+#[openbrush::trait_definition]
+pub trait CountingRankedChoice {
+    /// Create a new proposal with a list of options that can be ranked and the method used to
+    /// tally the votes.
+    ///
+    /// Emits a ProposalCreated event.
+    ///
+    /// Note: A proposal created through `propose` has no options and it cannot be voted.
+    #[ink(message)]
+    fn propose_with_options(
+        &mut self,
+        proposal: Proposal,
+        description: String,
+        options: Vec<String>,
+        mode: RankingMode,
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Minimum number of cast voted required for a proposal whose snapshot is at a specific
+    /// blockNumber to be successful.
+    ///
+    /// Note: The quorum is provided by the governor (by default from the voting module).
+    #[ink(message)]
+    fn quorum(&self, block_number: BlockNumber) -> Result<u64, GovernorError>;
+
+    /// Returns whether account has cast a vote on proposalId.
+    #[ink(message)]
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
+
+    /// Returns the options of a proposal, the index of an option is used to rank it.
+    #[ink(message)]
+    fn proposal_options(&self, proposal_id: ProposalId) -> Vec<String>;
+
+    /// Returns the method used to tally the votes of a proposal.
+    #[ink(message)]
+    fn ranking_mode(&self, proposal_id: ProposalId) -> Option<RankingMode>;
+
+    /// Returns the indexes of the winning options of a proposal, more than one if they are tied.
+    ///
+    /// Note: The result is final only once the voting period of the proposal is over.
+    #[ink(message)]
+    fn winning_options(&self, proposal_id: ProposalId) -> Result<Vec<u8>, GovernorError>;
+
+    /// Returns the results of every round of the tally of a proposal.
+    ///
+    /// Note: The result is final only once the voting period of the proposal is over.
+    #[ink(message)]
+    fn rounds(&self, proposal_id: ProposalId) -> Result<Vec<Round>, GovernorError>;
+
+    /// Tally the votes of a proposal whose voting period is over and store its winning options,
+    /// that are used by the state of the proposal.
+    ///
+    /// Note: Until an instant-runoff proposal is tallied it is defeated once its voting period is
+    /// over.
+    #[ink(message)]
+    fn tally(&mut self, proposal_id: ProposalId) -> Result<Vec<u8>, GovernorError>;
+}
@@ -1,9 +1,15 @@
-use openbrush::traits::AccountId;
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+    Hash,
+};
 
 use crate::traits::{
     errors::{
         CountingError,
         CountingSimpleError,
+        GovernorError,
     },
     governance::ProposalId,
 };
@@ -45,17 +51,67 @@ pub struct ProposalVote {
     pub abstain_votes: u64,
 }
 
+/// A VoteReceipt is the rapresentation of the vote cast by an account on a proposal.
+#[derive(Debug, Default, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct VoteReceipt {
+    /// The vote type cast.
+    pub support: u8,
+    /// The weight of the vote cast.
+    pub weight: u64,
+    /// The blake2x256 hash of the params of the vote cast.
+    pub params_hash: Hash,
+}
+
+/// A SuccessThreshold is the minimum fraction of the non-abstain votes that the for votes of a
+/// proposal must reach to be successful.
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct SuccessThreshold {
+    /// The numerator of the fraction.
+    pub numerator: u64,
+    /// The denominator of the fraction.
+    pub denominator: u64,
+}
+
+impl Default for SuccessThreshold {
+    /// The simple majority.
+    fn default() -> Self {
+        SuccessThreshold {
+            numerator: 1,
+            denominator: 2,
+        }
+    }
+}
+
 #[openbrush::wrapper]
 pub type CountingSimpleRef = dyn CountingSimple;
 
 /// Trait definition of counting simple module.
+///
+/// A proposal is successful if its for votes are more than its against votes and they reach the
+/// success threshold of the proposal. Every transaction belongs to the class set through
+/// governance for its callee and selector, the class `0` if it is not set. A proposal stores at
+/// creation the strictest threshold of the classes of its transactions, the threshold of the class
+/// `0` if it has no transactions. The threshold of a class is the simple majority until it is set
+/// through governance.
+///
+/// A vote is final unless vote changing is enabled through governance: then a new vote of an
+/// account on an active proposal replaces its previous vote, or the vote can be retracted.
 #[openbrush::trait_definition]
 pub trait CountingSimple {
-    #[ink(message)]
-    /// Minimum number of cast voted required for a proposal to be successful.
+    /// Minimum number of cast voted required for a proposal whose snapshot is at a specific
+    /// blockNumber to be successful.
     ///
-    /// Note: In this module by default is 1 vote for simple group members without token involved
-    fn quorum(&self) -> u64;
+    /// Note: The quorum is provided by the governor (by default from the voting module).
+    #[ink(message)]
+    fn quorum(&self, block_number: BlockNumber) -> Result<u64, CountingSimpleError>;
     /// Returns whether account has cast a vote on proposalId.
     #[ink(message)]
     fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
@@ -66,4 +122,80 @@ pub trait CountingSimple {
         &self,
         proposal_id: ProposalId,
     ) -> Result<ProposalVote, CountingSimpleError>;
+
+    /// Returns the receipt of the vote of an account on a proposal, None if the account has not
+    /// voted.
+    #[ink(message)]
+    fn get_receipt(
+        &self,
+        proposal_id: ProposalId,
+        account: AccountId,
+    ) -> Option<VoteReceipt>;
+
+    /// Returns the number of accounts that have voted on a proposal, including the accounts that
+    /// retracted their vote.
+    #[ink(message)]
+    fn voter_count(&self, proposal_id: ProposalId) -> u32;
+
+    /// Returns at most `limit` voters of a proposal with the receipts of their votes, in order of
+    /// first vote, starting from the `offset` index.
+    #[ink(message)]
+    fn voters(
+        &self,
+        proposal_id: ProposalId,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(AccountId, VoteReceipt)>;
+
+    /// Returns the class of the transactions that call a selector of a callee.
+    #[ink(message)]
+    fn transaction_class(&self, callee: AccountId, selector: [u8; 4]) -> u8;
+
+    /// Update the class of the transactions that call a selector of a callee, the proposals
+    /// already created keep their threshold.
+    ///
+    /// Note: This must be done through governance.
+    #[ink(message)]
+    fn set_transaction_class(
+        &mut self,
+        callee: AccountId,
+        selector: [u8; 4],
+        class: u8,
+    ) -> Result<(), GovernorError>;
+
+    /// Returns the success threshold of a class of proposals.
+    #[ink(message)]
+    fn class_success_threshold(&self, class: u8) -> SuccessThreshold;
+
+    /// Update the success threshold of a class of proposals, the proposals already created keep
+    /// their threshold.
+    ///
+    /// Note: The threshold must be at least the simple majority.
+    /// Note: This must be done through governance.
+    #[ink(message)]
+    fn set_class_success_threshold(
+        &mut self,
+        class: u8,
+        threshold: SuccessThreshold,
+    ) -> Result<(), GovernorError>;
+
+    /// Returns the success threshold of a proposal.
+    #[ink(message)]
+    fn success_threshold(&self, proposal_id: ProposalId) -> SuccessThreshold;
+
+    /// Returns whether a new vote replaces the previous vote of an account.
+    #[ink(message)]
+    fn vote_changing(&self) -> bool;
+
+    /// Enable or disable vote changing, it applies also to the proposals already created.
+    ///
+    /// Note: This must be done through governance.
+    #[ink(message)]
+    fn set_vote_changing(&mut self, enabled: bool) -> Result<(), GovernorError>;
+
+    /// Retract the vote of the caller on an active proposal, only if vote changing is enabled.
+    ///
+    /// Emits a VoteRetracted event.
+    #[ink(message)]
+    fn retract_vote(&mut self, proposal_id: ProposalId) -> Result<(), GovernorError>;
 }
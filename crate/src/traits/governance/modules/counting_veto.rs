@@ -0,0 +1,128 @@
+use openbrush::traits::{
+    AccountId,
+    BlockNumber,
+};
+
+use crate::traits::{
+    errors::{
+        CountingError,
+        CountingSimpleError,
+        GovernorError,
+    },
+    governance::ProposalId,
+};
+
+/// The choices available to vote on a proposal with a veto
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum VetoVoteType {
+    Against,
+    For,
+    Abstain,
+    /// An against vote that also vetoes the proposal.
+    NoWithVeto,
+}
+
+impl TryFrom<u8> for VetoVoteType {
+    type Error = CountingError;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(VetoVoteType::Against),
+            2 => Ok(VetoVoteType::For),
+            3 => Ok(VetoVoteType::Abstain),
+            4 => Ok(VetoVoteType::NoWithVeto),
+            _ => Err(CountingError::InvalidVoteType),
+        }
+    }
+}
+
+/// A VetoProposalVote is the rapresentation of the votes a proposal with a veto may have.
+#[derive(Debug, Default, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct VetoProposalVote {
+    /// The votes against the proposal.
+    pub against_votes: u64,
+    /// The votes in favour of the proposal.
+    pub for_votes: u64,
+    /// The abstain votes.
+    pub abstain_votes: u64,
+    /// The against votes that also veto the proposal.
+    pub no_with_veto_votes: u64,
+}
+
+/// A VetoThreshold is the fraction of all the votes cast on a proposal that its no with veto votes
+/// must exceed to veto it.
+#[derive(Debug, Clone, Copy, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct VetoThreshold {
+    /// The numerator of the fraction.
+    pub numerator: u64,
+    /// The denominator of the fraction.
+    pub denominator: u64,
+}
+
+impl Default for VetoThreshold {
+    /// One third of the votes.
+    fn default() -> Self {
+        VetoThreshold {
+            numerator: 1,
+            denominator: 3,
+        }
+    }
+}
+
+#[openbrush::wrapper]
+pub type CountingVetoRef = dyn CountingVeto;
+
+/// Trait definition of counting veto module.
+///
+/// Counting simple with a fourth vote type, `NoWithVeto`, that counts as an against vote and also
+/// vetoes the proposal. A proposal is vetoed if its no with veto votes exceed the veto threshold
+/// of all the votes cast, a vetoed proposal is defeated regardless of its for votes and its
+/// deposit is slashed instead of refunded.
+#[openbrush::trait_definition]
+pub trait CountingVeto {
+    /// Minimum number of cast voted required for a proposal whose snapshot is at a specific
+    /// blockNumber to be successful.
+    ///
+    /// Note: The quorum is provided by the governor (by default from the voting module).
+    #[ink(message)]
+    fn quorum(&self, block_number: BlockNumber) -> Result<u64, CountingSimpleError>;
+
+    /// Returns whether account has cast a vote on proposalId.
+    #[ink(message)]
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
+
+    /// Returns the votes that a proposal has already received
+    #[ink(message)]
+    fn proposal_votes(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<VetoProposalVote, CountingSimpleError>;
+
+    /// Returns whether the no with veto votes of a proposal exceed the veto threshold.
+    ///
+    /// Note: The result is final only once the voting period of the proposal is over.
+    #[ink(message)]
+    fn vetoed(&self, proposal_id: ProposalId) -> bool;
+
+    /// Returns the current veto threshold.
+    #[ink(message)]
+    fn veto_threshold(&self) -> VetoThreshold;
+
+    /// Update the veto threshold, it applies also to the proposals already created.
+    ///
+    /// Note: This must be done through governance.
+    #[ink(message)]
+    fn set_veto_threshold(
+        &mut self,
+        threshold: VetoThreshold,
+    ) -> Result<(), GovernorError>;
+}
@@ -12,4 +12,20 @@ pub trait Voter {
         block_number: BlockNumber,
         params: &[u8],
     ) -> Option<u64>;
+
+    /// Get the minimum number of votes required for a proposal whose snapshot is at a specific
+    /// blockNumber, None if the module does not provide a quorum.
+    ///
+    /// Note: By default the voting modules do not provide a quorum.
+    fn _quorum(&self, _block_number: BlockNumber) -> Option<u64> {
+        None
+    }
+
+    /// Get the total supply of voting weight at a specific blockNumber, None if the module does
+    /// not track it.
+    ///
+    /// Note: By default the voting modules do not track the total supply.
+    fn _get_past_total_supply(&self, _block_number: BlockNumber) -> Option<u64> {
+        None
+    }
 }
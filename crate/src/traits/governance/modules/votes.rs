@@ -0,0 +1,14 @@
+use openbrush::traits::AccountId;
+
+/// Wrapper to simplify cross-contract call
+#[openbrush::wrapper]
+pub type GovernorVotesRef = dyn GovernorVotes;
+
+/// Trait definition of governor votes module, that extracts the voting weight from a separate
+/// token contract implementing Votes (e.g. PSP22Votes).
+#[openbrush::trait_definition]
+pub trait GovernorVotes {
+    /// Returns the address of the token used as source of the voting weight.
+    #[ink(message)]
+    fn token(&self) -> AccountId;
+}
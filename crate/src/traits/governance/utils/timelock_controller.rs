@@ -0,0 +1,149 @@
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::traits::access_control::*,
+    traits::{
+        Hash,
+        Timestamp,
+    },
+};
+
+use crate::traits::errors::TimelockControllerError;
+pub use crate::traits::governance::Transaction;
+
+/// Hash type which identifies an unique id for an operation
+pub type OperationId = Hash;
+
+/// Role that administers all the other roles of the timelock
+pub const TIMELOCK_ADMIN_ROLE: RoleType = ink::selector_id!("TIMELOCK_ADMIN_ROLE");
+/// Role that can schedule operations
+pub const PROPOSER_ROLE: RoleType = ink::selector_id!("PROPOSER_ROLE");
+/// Role that can execute ready operations (if granted to the zero address anyone can execute)
+pub const EXECUTOR_ROLE: RoleType = ink::selector_id!("EXECUTOR_ROLE");
+/// Role that can cancel pending operations
+pub const CANCELLER_ROLE: RoleType = ink::selector_id!("CANCELLER_ROLE");
+
+/// Wrapper to simplify cross-contract call
+#[openbrush::wrapper]
+pub type TimelockControllerRef = dyn TimelockController + AccessControl;
+
+/// Trait definition for all contract that act as a time-lock of operations.
+#[openbrush::trait_definition]
+pub trait TimelockController: AccessControl {
+    /// Returns the minimum delay for an operation to become valid.
+    #[ink(message)]
+    fn get_min_delay(&self) -> Timestamp;
+
+    /// Returns the timestamp at which an operation becomes ready (0 for unset operations, 1 for
+    /// done operations).
+    #[ink(message)]
+    fn get_timestamp(&self, id: OperationId) -> Timestamp;
+
+    /// Returns whether an id correspond to a registered operation. This includes both Pending,
+    /// Ready and Done operations.
+    #[ink(message)]
+    fn is_operation(&self, id: OperationId) -> bool;
+
+    /// Returns whether an operation is pending or not.
+    #[ink(message)]
+    fn is_operation_pending(&self, id: OperationId) -> bool;
+
+    /// Returns whether an operation is ready or not.
+    #[ink(message)]
+    fn is_operation_ready(&self, id: OperationId) -> bool;
+
+    /// Returns whether an operation is done or not.
+    #[ink(message)]
+    fn is_operation_done(&self, id: OperationId) -> bool;
+
+    /// Returns the identifier of an operation containing a single transaction.
+    #[ink(message)]
+    fn hash_operation(
+        &self,
+        transaction: Transaction,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+    ) -> OperationId;
+
+    /// Returns the identifier of an operation containing a batch of transactions.
+    #[ink(message)]
+    fn hash_operation_batch(
+        &self,
+        transactions: Vec<Transaction>,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+    ) -> OperationId;
+
+    /// Schedule an operation containing a single transaction.
+    ///
+    /// Emits a CallScheduled event.
+    ///
+    /// Note: The caller must have the proposer role.
+    #[ink(message)]
+    fn schedule(
+        &mut self,
+        transaction: Transaction,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+        delay: Timestamp,
+    ) -> Result<(), TimelockControllerError>;
+
+    /// Schedule an operation containing a batch of transactions.
+    ///
+    /// Emits one CallScheduled event per transaction in the batch.
+    ///
+    /// Note: The caller must have the proposer role.
+    #[ink(message)]
+    fn schedule_batch(
+        &mut self,
+        transactions: Vec<Transaction>,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+        delay: Timestamp,
+    ) -> Result<(), TimelockControllerError>;
+
+    /// Cancel an operation.
+    ///
+    /// Emits a Cancelled event.
+    ///
+    /// Note: The caller must have the canceller role.
+    #[ink(message)]
+    fn cancel(&mut self, id: OperationId) -> Result<(), TimelockControllerError>;
+
+    /// Execute an (ready) operation containing a single transaction.
+    ///
+    /// Emits a CallExecuted event.
+    ///
+    /// Note: The caller must have the executor role.
+    #[ink(message, payable)]
+    fn execute(
+        &mut self,
+        transaction: Transaction,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+    ) -> Result<(), TimelockControllerError>;
+
+    /// Execute an (ready) operation containing a batch of transactions.
+    ///
+    /// Emits one CallExecuted event per transaction in the batch.
+    ///
+    /// Note: The caller must have the executor role.
+    #[ink(message, payable)]
+    fn execute_batch(
+        &mut self,
+        transactions: Vec<Transaction>,
+        predecessor: Option<OperationId>,
+        salt: Hash,
+    ) -> Result<(), TimelockControllerError>;
+
+    /// Changes the minimum timelock duration for future operations.
+    ///
+    /// Emits a MinDelayChange event.
+    ///
+    /// Note: The caller must be the timelock itself. This can only be achieved by scheduling and
+    /// later executing an operation where the timelock is the callee.
+    #[ink(message)]
+    fn update_delay(
+        &mut self,
+        new_delay: Timestamp,
+    ) -> Result<(), TimelockControllerError>;
+}
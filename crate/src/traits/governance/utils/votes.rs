@@ -4,10 +4,25 @@ use openbrush::traits::{
     BlockNumber,
 };
 
+pub use crate::traits::signature::Signature;
+
 use crate::traits::errors::VotesError;
 
 pub type Vote = u64;
 
+/// A Delegation is the change of delegate signed off-chain by a delegator to be submitted by a
+/// relayer
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Delegation {
+    /// The account that receives the voting power.
+    pub delegatee: AccountId,
+    /// The current nonce of the delegator, it prevents the replay of the signature.
+    pub nonce: u64,
+    /// The last block number at which the signature can be used.
+    pub expiry: BlockNumber,
+}
+
 /// Wrapper to simplify cross-contract call
 #[openbrush::wrapper]
 pub type VotesRef = dyn Votes;
@@ -47,6 +62,31 @@ pub trait Votes {
     fn delegate(&mut self, delegatee: AccountId) -> Result<(), VotesError>;
 }
 
+/// Wrapper to simplify cross-contract call
+#[openbrush::wrapper]
+pub type DelegateBySigRef = dyn DelegateBySig;
+
+/// Trait definition of the delegation by signature, an optional extension of `Votes`.
+#[openbrush::trait_definition]
+pub trait DelegateBySig {
+    /// Returns the current nonce of an account. The nonce must be included in every delegation
+    /// signed by the account and it is incremented every time a signature is used.
+    #[ink(message)]
+    fn delegation_nonces(&self, account: AccountId) -> u64;
+
+    /// Delegates votes from `delegator` to `delegatee` using the delegator's signature of the
+    /// delegation, so that the delegation can be submitted by any account.
+    #[ink(message)]
+    fn delegate_by_sig(
+        &mut self,
+        delegator: AccountId,
+        delegatee: AccountId,
+        nonce: u64,
+        expiry: BlockNumber,
+        signature: Signature,
+    ) -> Result<(), VotesError>;
+}
+
 pub fn balance_to_vote(input: Balance) -> Option<u64> {
     TryInto::<u64>::try_into(input).ok()
 }
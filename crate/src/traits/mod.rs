@@ -2,5 +2,7 @@
 pub mod errors;
 /// Governance modules traits
 pub mod governance;
+/// Off-chain signatures verification
+pub mod signature;
 /// Token related traits
 pub mod token;
@@ -0,0 +1,56 @@
+use ink::env::hash::{
+    Blake2x256,
+    HashOutput,
+};
+use openbrush::traits::AccountId;
+
+/// A signature of a message produced off-chain by the owner of an account.
+///
+/// Note: The sr25519 signatures are verified only with the `sr25519` feature, because
+/// `sr25519_verify` is an unstable host function of pallet-contracts that the chain must enable.
+/// Without the feature they are always invalid.
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Signature {
+    /// An sr25519 signature, the account is the public key of the signer (see the `sr25519`
+    /// feature).
+    Sr25519([u8; 64]),
+    /// An ecdsa (secp256k1) signature, the account is the blake2x256 hash of the compressed
+    /// public key of the signer.
+    Ecdsa([u8; 65]),
+}
+
+/// Returns the blake2x256 hash of a SCALE encoded payload. This is the message that is signed
+/// off-chain.
+pub fn hash_payload<P: scale::Encode>(payload: &P) -> [u8; 32] {
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink::env::hash_encoded::<Blake2x256, _>(payload, &mut output);
+    output
+}
+
+/// Returns true if `signature` is a valid signature of `message_hash` by `signer`.
+pub fn verify(
+    signer: &AccountId,
+    message_hash: &[u8; 32],
+    signature: &Signature,
+) -> bool {
+    match signature {
+        #[cfg(feature = "sr25519")]
+        Signature::Sr25519(signature) => {
+            ink::env::sr25519_verify(signature, message_hash, signer.as_ref()).is_ok()
+        }
+        #[cfg(not(feature = "sr25519"))]
+        Signature::Sr25519(_) => false,
+        Signature::Ecdsa(signature) => {
+            let mut public_key = [0; 33];
+            if ink::env::ecdsa_recover(signature, message_hash, &mut public_key).is_err()
+            {
+                return false
+            }
+
+            let mut recovered = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Blake2x256>(&public_key, &mut recovered);
+            AccountId::from(recovered) == *signer
+        }
+    }
+}
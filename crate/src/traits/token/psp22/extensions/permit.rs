@@ -0,0 +1,60 @@
+use openbrush::{
+    contracts::traits::psp22::*,
+    traits::{
+        AccountId,
+        Balance,
+        BlockNumber,
+        Hash,
+    },
+};
+
+pub use crate::traits::{
+    errors::PSP22PermitError,
+    signature::Signature,
+};
+
+/// A Permit is the approval signed off-chain by an owner to be submitted by a relayer
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Permit {
+    /// The account that owns the tokens.
+    pub owner: AccountId,
+    /// The account that is allowed to spend the tokens.
+    pub spender: AccountId,
+    /// The allowance of the spender.
+    pub value: Balance,
+    /// The current nonce of the owner, it prevents the replay of the signature.
+    pub nonce: u64,
+    /// The last block number at which the signature can be used.
+    pub deadline: BlockNumber,
+}
+
+/// Wrapper to simplify cross-contract call.
+#[openbrush::wrapper]
+pub type PSP22PermitRef = dyn PSP22Permit + PSP22;
+
+/// Trait definition of PSP22Permit extension.
+///
+/// The nonces of the owners are shared with the other signed actions of the token (e.g.
+/// `DelegateBySig::delegate_by_sig`) and can be queried with `DelegateBySig::delegation_nonces`.
+#[openbrush::trait_definition]
+pub trait PSP22Permit: PSP22 {
+    /// Sets `value` as the allowance of `spender` over the tokens of `owner` using the owner's
+    /// signature of the permit, so that the approval can be submitted by any account.
+    ///
+    /// Emits an Approval event.
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: BlockNumber,
+        signature: Signature,
+    ) -> Result<(), PSP22PermitError>;
+
+    /// Returns the domain separator included in every signed permit, it binds the signature to
+    /// this token contract.
+    #[ink(message)]
+    fn domain_separator(&self) -> Hash;
+}
@@ -1,7 +1,9 @@
+use ink::prelude::vec::Vec;
 use openbrush::{
     contracts::traits::psp22::*,
     traits::{
         AccountId,
+        Balance,
         BlockNumber,
     },
 };
@@ -24,11 +26,28 @@ pub struct Checkpoint {
     pub votes: Vote,
 }
 
+/// A Lock is an amount of tokens of an account that cannot be transferred until the lock is
+/// removed, a lock can be removed only after its expiration.
+#[derive(Debug, Default, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Lock {
+    /// The amount of tokens locked.
+    pub amount: Balance,
+    /// The block number at which the lock expires.
+    pub until: BlockNumber,
+}
+
 /// Wrapper to simplify cross-contract call.
 #[openbrush::wrapper]
 pub type PSP22VotesRef = dyn PSP22Votes + Votes + PSP22;
 
 /// Trait definition of PSP22Votes extension.
+///
+/// Note: The locks block the transfers only if the user overrides the `_before_token_transfer`
+/// method of psp22::Internal to call `_before_token_transfer_locks`.
 #[openbrush::trait_definition]
 pub trait PSP22Votes: Votes + PSP22 {
     /// Get the pos-th checkpoint for account.
@@ -42,6 +61,44 @@ pub trait PSP22Votes: Votes + PSP22 {
     /// Get number of checkpoints for account.
     #[ink(message)]
     fn num_checkpoints(&self, account: AccountId) -> Result<u32, PSP22VotesError>;
+
+    /// Returns the account allowed to lock the tokens of the holders (e.g. a governor that
+    /// counts votes with conviction).
+    #[ink(message)]
+    fn locker(&self) -> Option<AccountId>;
+
+    /// Transfer the locker role to `locker`. Only the current locker can perform this operation.
+    #[ink(message)]
+    fn set_locker(&mut self, locker: AccountId) -> Result<(), PSP22VotesError>;
+
+    /// Lock `amount` tokens of `account` until the block number `until`, the amount cannot
+    /// exceed the balance of the account. Only the locker can perform this operation.
+    ///
+    /// Note: The locks of an account overlap, the locked balance is the amount of its largest
+    /// lock.
+    ///
+    /// Emits a TokensLocked event.
+    #[ink(message)]
+    fn lock(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+        until: BlockNumber,
+    ) -> Result<(), PSP22VotesError>;
+
+    /// Returns the locks of `account` that have not been removed yet.
+    #[ink(message)]
+    fn locks(&self, account: AccountId) -> Vec<Lock>;
+
+    /// Returns the balance of `account` that cannot be transferred.
+    #[ink(message)]
+    fn locked_balance(&self, account: AccountId) -> Balance;
+
+    /// Remove the expired locks of `account`. Anyone can remove them.
+    ///
+    /// Emits a TokensUnlocked event.
+    #[ink(message)]
+    fn unlock(&mut self, account: AccountId) -> Result<(), PSP22VotesError>;
 }
 
 /// Utility function to safe convert from u32 type to usize.
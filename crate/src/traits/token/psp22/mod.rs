@@ -1,4 +1,5 @@
 /// Traits definition of extensions of psp22 base contracts.  
 pub mod extensions {
+    pub mod permit;
     pub mod votes;
 }
@@ -0,0 +1,279 @@
+use ink::env::{
+    test::DefaultAccounts,
+    DefaultEnvironment,
+};
+
+use crate::gov_approval::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_counting_approval::*,
+    governor_voting_group::*,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 2,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 1,
+    };
+    let charlie_member = VotingMember {
+        account: accounts.charlie,
+        voting_power: 1,
+    };
+    let django_member = VotingMember {
+        account: accounts.django,
+        voting_power: 1,
+    };
+
+    let init_members = vec![alice_member, bob_member, charlie_member, django_member];
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, init_members).unwrap()
+}
+
+fn options() -> Vec<String> {
+    vec![
+        String::from("Option A"),
+        String::from("Option B"),
+        String::from("Option C"),
+        String::from("Option D"),
+    ]
+}
+
+fn propose(contract: &mut Contract) -> ProposalId {
+    let accounts = default_accounts();
+
+    set_caller(accounts.bob);
+    let proposal = Proposal::default();
+    let description = String::from("Test proposal");
+    contract
+        .propose_with_options(proposal, description, options(), 2)
+        .unwrap()
+}
+
+fn approve(contract: &mut Contract, proposal_id: ProposalId, approvals: u8) {
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            vec![approvals],
+        )
+        .unwrap();
+}
+
+#[ink::test]
+/// Propose with options works correctly
+fn propose_with_options_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    set_caller(accounts.bob);
+    for winners in [0, 5] {
+        let err_response = contract
+            .propose_with_options(
+                Proposal::default(),
+                String::from("Test proposal"),
+                options(),
+                winners,
+            )
+            .unwrap_err();
+        assert_eq!(err_response, GovernorError::InvalidProposalOptions);
+    }
+
+    let proposal_id = propose(&mut contract);
+    assert_eq!(contract.proposal_options(proposal_id), options());
+    assert_eq!(contract.number_of_winners(proposal_id), 2);
+    assert_eq!(
+        contract.proposal_votes(proposal_id).unwrap(),
+        ApprovalVotes {
+            option_votes: vec![0, 0, 0, 0],
+            total_votes: 0,
+        }
+    );
+    assert_eq!(contract.winning_options(proposal_id), Vec::<u8>::new());
+}
+
+#[ink::test]
+/// Every approved option receives the whole weight and the options with the most votes win
+fn approval_vote_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    approve(&mut contract, proposal_id, 0b0011);
+    set_caller(accounts.bob);
+    approve(&mut contract, proposal_id, 0b0110);
+    set_caller(accounts.charlie);
+    approve(&mut contract, proposal_id, 0b0101);
+    // A vote without params approves only the support
+    set_caller(accounts.django);
+    contract.cast_vote(proposal_id, 1).unwrap();
+
+    assert_eq!(
+        contract.proposal_votes(proposal_id).unwrap(),
+        ApprovalVotes {
+            option_votes: vec![3, 4, 2, 0],
+            total_votes: 5,
+        }
+    );
+    assert!(contract.has_voted(proposal_id, accounts.charlie));
+    assert_eq!(contract.winning_options(proposal_id), vec![1, 0]);
+
+    // The voting period is over
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Succeeded
+    );
+}
+
+#[ink::test]
+/// A tie between a winning option and an option that does not win defeats the proposal
+fn tied_options_are_defeated() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    approve(&mut contract, proposal_id, 0b0001);
+    set_caller(accounts.bob);
+    approve(&mut contract, proposal_id, 0b0010);
+    set_caller(accounts.charlie);
+    approve(&mut contract, proposal_id, 0b0100);
+
+    assert_eq!(contract.winning_options(proposal_id), Vec::<u8>::new());
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
+
+#[ink::test]
+/// The bitmap can approve only the options of the proposal
+fn approve_missing_option_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(proposal_id, 0, String::from(""), vec![0b10001])
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::InvalidVoteType"))
+    );
+}
+
+#[ink::test]
+/// The bitmap must have a bit for every option
+fn invalid_bitmap_length_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            vec![0b0001, 0],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::InvalidParams"))
+    );
+}
+
+#[ink::test]
+/// The bitmap must approve at least one option
+fn empty_bitmap_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(proposal_id, 0, String::from(""), vec![0])
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::InvalidParams"))
+    );
+    assert!(!contract.has_voted(proposal_id, accounts.alice));
+}
+
+#[ink::test]
+/// The votes of a proposal cannot overflow
+fn votes_overflow_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: u64::MAX,
+    };
+    contract.update_members(vec![bob_member], vec![]).unwrap();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    approve(&mut contract, proposal_id, 0b0001);
+    set_caller(accounts.bob);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(proposal_id, 0, String::from(""), vec![0b0010])
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VotesOverflow"))
+    );
+    assert!(!contract.has_voted(proposal_id, accounts.bob));
+}
+
+#[ink::test]
+/// A proposal without votes is defeated once the voting period is over
+fn proposal_without_votes_is_defeated() {
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
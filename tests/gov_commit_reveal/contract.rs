@@ -0,0 +1,282 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+#[openbrush::contract]
+pub mod gov_commit_reveal {
+
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::vec::Vec,
+    };
+    use ink_governance::{
+        governor::*,
+        governor_commit_reveal::*,
+        governor_counting_simple::*,
+        governor_voting_group::*,
+    };
+    use openbrush::traits::{
+        Storage,
+        String,
+    };
+
+    /// Emitted when a proposal is create
+    #[ink(event)]
+    pub struct ProposalCreated {
+        /// The account that created the proposal.
+        #[ink(topic)]
+        pub proposer: AccountId,
+        /// The id of the created proposal.
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        /// The proposal created.
+        pub proposal: Proposal,
+        /// The block number when the proposal start.
+        pub start_block: BlockNumber,
+        /// The block number when the proposal end.
+        pub end_block: BlockNumber,
+        /// Description of the proposal
+        pub description: String,
+    }
+
+    /// Emitted when a proposal is cancel
+    #[ink(event)]
+    pub struct ProposalCanceled {
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+    }
+
+    /// Emitted when a proposal is execute
+    #[ink(event)]
+    pub struct ProposalExecuted {
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+    }
+
+    /// Emitted when a vote is cast
+    #[ink(event)]
+    pub struct VoteCasted {
+        /// The account who cast the vote of the proposal.
+        #[ink(topic)]
+        pub voter: AccountId,
+        /// The id of the proposal.
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        /// The vote type casted.
+        pub support: u8,
+        /// The weight of the vote cast.
+        pub weight: u64,
+        /// Reason of the vote.
+        pub reason: String,
+    }
+
+    /// Emitted when a vote is cast with params
+    #[ink(event)]
+    pub struct VoteCastedWithParams {
+        /// The account who cast the vote of the proposal.
+        #[ink(topic)]
+        pub voter: AccountId,
+        /// The id of the proposal.
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        /// The vote type casted.
+        pub support: u8,
+        /// The weight of the vote cast.
+        pub weight: u64,
+        /// Reason of the vote.
+        pub reason: String,
+        /// Params of the vote.
+        pub params: Vec<u8>,
+    }
+
+    /// Emitted when a vote is committed
+    #[ink(event)]
+    pub struct VoteCommitted {
+        /// The account who committed the vote.
+        #[ink(topic)]
+        pub voter: AccountId,
+        /// The id of the proposal.
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+    }
+
+    /// Emitted when a new reveal period is set
+    #[ink(event)]
+    pub struct RevealPeriodSet {
+        /// The old reveal period.
+        pub old_reveal_period: BlockNumber,
+        /// The new reveal period.
+        pub new_reveal_period: BlockNumber,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        governor: governor::Data<
+            governor_counting_simple::Counting,
+            governor_voting_group::Voting,
+        >,
+        #[storage_field]
+        commit_reveal: governor_commit_reveal::Data,
+    }
+
+    impl Governor for Contract {
+        #[ink(message)]
+        fn state(&self, proposal_id: ProposalId) -> Result<ProposalState, GovernorError> {
+            self._commit_reveal_state(&proposal_id)
+        }
+    }
+
+    impl GovernorCommitReveal for Contract {}
+
+    impl VotingGroup for Contract {}
+
+    impl CountingSimple for Contract {}
+
+    // Override the internal methods
+    impl governor::Internal for Contract {
+        fn _voting_delay(&self) -> u32 {
+            0 // block
+        }
+        fn _voting_period(&self) -> u32 {
+            2 // block (for testing purpose)
+        }
+        // The votes must be committed and then revealed
+        fn _cast_vote_with_params(
+            &mut self,
+            _proposal_id: &ProposalId,
+            _account: &AccountId,
+            _support: u8,
+            _reason: &String,
+            _params: &[u8],
+        ) -> Result<u64, GovernorError> {
+            Err(GovernorError::VoteMustBeCommitted)
+        }
+        fn _emit_proposal_created(
+            &self,
+            proposer: AccountId,
+            proposal_id: ProposalId,
+            proposal: Proposal,
+            start_block: BlockNumber,
+            end_block: BlockNumber,
+            description: String,
+        ) {
+            self.env().emit_event(ProposalCreated {
+                proposer,
+                proposal_id,
+                proposal,
+                start_block,
+                end_block,
+                description,
+            })
+        }
+        fn _emit_vote_cast(
+            &self,
+            voter: AccountId,
+            proposal_id: ProposalId,
+            support: u8,
+            weight: u64,
+            reason: String,
+        ) {
+            self.env().emit_event(VoteCasted {
+                voter,
+                proposal_id,
+                support,
+                weight,
+                reason,
+            })
+        }
+        fn _emit_vote_cast_with_params(
+            &self,
+            voter: AccountId,
+            proposal_id: ProposalId,
+            support: u8,
+            weight: u64,
+            reason: String,
+            params: Vec<u8>,
+        ) {
+            self.env().emit_event(VoteCastedWithParams {
+                voter,
+                proposal_id,
+                support,
+                weight,
+                reason,
+                params,
+            })
+        }
+        fn _emit_proposal_canceled(&self, proposal_id: ProposalId) {
+            self.env().emit_event(ProposalCanceled { proposal_id })
+        }
+        fn _emit_proposal_executed(&self, proposal_id: ProposalId) {
+            self.env().emit_event(ProposalExecuted { proposal_id })
+        }
+    }
+
+    impl governor_commit_reveal::Internal for Contract {
+        fn _emit_vote_committed(&self, voter: AccountId, proposal_id: ProposalId) {
+            self.env().emit_event(VoteCommitted { voter, proposal_id })
+        }
+        fn _emit_reveal_period_set(
+            &self,
+            old_reveal_period: BlockNumber,
+            new_reveal_period: BlockNumber,
+        ) {
+            self.env().emit_event(RevealPeriodSet {
+                old_reveal_period,
+                new_reveal_period,
+            })
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ContractError {
+        Custom(String),
+        VotingGroupError(VotingGroupError),
+    }
+
+    impl From<VotingGroupError> for ContractError {
+        fn from(voting: VotingGroupError) -> Self {
+            match voting {
+                VotingGroupError::NoMember => {
+                    ContractError::Custom(String::from("VG: NoMember"))
+                }
+                _ => ContractError::Custom(String::from("VG: VotingGroupError")),
+            }
+        }
+    }
+
+    impl Contract {
+        /// Initialize the contract with a list of voting members, optional admin (if not set
+        /// the caller will be the admin by default) and the reveal period
+        #[ink(constructor)]
+        pub fn new(
+            admin: Option<AccountId>,
+            init_members: Vec<VotingMember>,
+            reveal_period: BlockNumber,
+        ) -> Result<Self, ContractError> {
+            let mut instance = Self::default();
+
+            // Assign the admin role to the caller if is not set in the parameters
+            let admin = admin.unwrap_or(Self::env().caller());
+
+            // Initialize the group with the members.
+            //
+            // Note: Only the members of the group can propose or vote a proposal.
+            governor_voting_group::VotingGroup::_init_members(
+                &mut instance,
+                admin,
+                init_members,
+            )?;
+
+            instance._init_with_reveal_period(reveal_period);
+
+            Ok(instance)
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests;
@@ -0,0 +1,214 @@
+use ink::env::{
+    test::DefaultAccounts,
+    DefaultEnvironment,
+};
+
+use crate::gov_commit_reveal::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        Hash,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_commit_reveal::*,
+    governor_counting_simple::*,
+    governor_voting_group::*,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 2,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 1,
+    };
+    let charlie_member = VotingMember {
+        account: accounts.charlie,
+        voting_power: 1,
+    };
+
+    let init_members = vec![alice_member, bob_member, charlie_member];
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, init_members, 2).unwrap()
+}
+
+fn propose(contract: &mut Contract) -> ProposalId {
+    let accounts = default_accounts();
+
+    set_caller(accounts.bob);
+    let proposal = Proposal::default();
+    let description = String::from("Test proposal");
+    contract.propose(proposal, description).unwrap()
+}
+
+fn commit(
+    contract: &mut Contract,
+    proposal_id: ProposalId,
+    voter: AccountId,
+    support: u8,
+) {
+    set_caller(voter);
+    let commitment = contract.hash_vote(
+        proposal_id,
+        voter,
+        support,
+        Hash::from([support; 32]),
+        Vec::new(),
+    );
+    contract.commit_vote(proposal_id, commitment).unwrap();
+}
+
+fn reveal(
+    contract: &mut Contract,
+    proposal_id: ProposalId,
+    voter: AccountId,
+    support: u8,
+) -> Result<u64, GovernorError> {
+    set_caller(voter);
+    contract.reveal_vote(proposal_id, support, Hash::from([support; 32]), Vec::new())
+}
+
+fn advance_blocks(blocks: u32) {
+    for _ in 0..blocks {
+        ink::env::test::advance_block::<DefaultEnvironment>();
+    }
+}
+
+#[ink::test]
+fn set_reveal_period_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    assert_eq!(contract.reveal_period(), 2);
+    contract.set_reveal_period(5).unwrap();
+    assert_eq!(contract.reveal_period(), 5);
+
+    set_caller(accounts.bob);
+    let err_response = contract.set_reveal_period(1).unwrap_err();
+    assert_eq!(err_response, GovernorError::OnlyGovernance);
+}
+
+#[ink::test]
+/// The votes are hidden during the voting period and counted when revealed
+fn commit_and_reveal_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    // The votes cannot be cast directly
+    set_caller(accounts.alice);
+    let err_response = contract.cast_vote(proposal_id, 2).unwrap_err();
+    assert_eq!(err_response, GovernorError::VoteMustBeCommitted);
+
+    commit(&mut contract, proposal_id, accounts.alice, 2);
+    commit(&mut contract, proposal_id, accounts.bob, 1);
+    assert!(contract
+        .vote_commitment(proposal_id, accounts.alice)
+        .is_some());
+    assert!(contract.proposal_votes(proposal_id).is_err());
+
+    let err_response = reveal(&mut contract, proposal_id, accounts.alice, 2).unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotRevealing);
+
+    // The voting period is over
+    advance_blocks(3);
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Revealing
+    );
+
+    let err_response = contract
+        .commit_vote(proposal_id, Hash::default())
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotActive);
+
+    // The revealed vote must match the commitment
+    let err_response = reveal(&mut contract, proposal_id, accounts.alice, 1).unwrap_err();
+    assert_eq!(err_response, GovernorError::InvalidReveal);
+    let err_response =
+        reveal(&mut contract, proposal_id, accounts.charlie, 2).unwrap_err();
+    assert_eq!(err_response, GovernorError::NoCommitment);
+
+    assert_eq!(reveal(&mut contract, proposal_id, accounts.alice, 2), Ok(2));
+    assert_eq!(reveal(&mut contract, proposal_id, accounts.bob, 1), Ok(1));
+    assert_eq!(contract.vote_commitment(proposal_id, accounts.alice), None);
+
+    let err_response = reveal(&mut contract, proposal_id, accounts.alice, 2).unwrap_err();
+    assert_eq!(err_response, GovernorError::NoCommitment);
+
+    let proposal_votes = contract.proposal_votes(proposal_id).unwrap();
+    assert_eq!(proposal_votes.for_votes, 2);
+    assert_eq!(proposal_votes.against_votes, 1);
+
+    // The reveal window is over
+    advance_blocks(2);
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Succeeded
+    );
+}
+
+#[ink::test]
+/// The commitments that are not revealed are not counted
+fn unrevealed_commitments_are_not_counted() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    commit(&mut contract, proposal_id, accounts.alice, 2);
+    commit(&mut contract, proposal_id, accounts.bob, 1);
+
+    advance_blocks(3);
+    assert_eq!(reveal(&mut contract, proposal_id, accounts.bob, 1), Ok(1));
+
+    advance_blocks(2);
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+
+    let err_response = reveal(&mut contract, proposal_id, accounts.alice, 2).unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotRevealing);
+}
+
+#[ink::test]
+/// A commitment copied by another account cannot be revealed
+fn copied_commitment_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    commit(&mut contract, proposal_id, accounts.alice, 2);
+    let commitment = contract
+        .vote_commitment(proposal_id, accounts.alice)
+        .unwrap();
+    set_caller(accounts.bob);
+    contract.commit_vote(proposal_id, commitment).unwrap();
+
+    advance_blocks(3);
+    let err_response = reveal(&mut contract, proposal_id, accounts.bob, 2).unwrap_err();
+    assert_eq!(err_response, GovernorError::InvalidReveal);
+    assert_eq!(reveal(&mut contract, proposal_id, accounts.alice, 2), Ok(2));
+}
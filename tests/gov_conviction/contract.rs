@@ -0,0 +1,77 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+#[openbrush::contract]
+pub mod gov_conviction {
+
+    use ink_governance::{
+        governor::*,
+        governor_counting_conviction::*,
+        governor_votes::*,
+        governor_votes_quorum_fraction::*,
+    };
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        governor: governor::Data<
+            governor_counting_conviction::Counting,
+            governor_votes::Voting,
+        >,
+        #[storage_field]
+        quorum_fraction: governor_votes_quorum_fraction::Data,
+    }
+
+    impl Governor for Contract {}
+    impl CountingConviction for Contract {}
+    impl GovernorVotes for Contract {}
+    impl GovernorVotesQuorumFraction for Contract {}
+
+    // Override the internal methods
+    impl governor::Internal for Contract {
+        fn _voting_delay(&self) -> u32 {
+            0 // block
+        }
+        fn _voting_period(&self) -> u32 {
+            2 // block (for testing purpose)
+        }
+        fn _quorum(&self, block_number: BlockNumber) -> Result<u64, GovernorError> {
+            self._quorum_fraction(block_number)
+        }
+    }
+
+    impl Contract {
+        /// Initialize the contract with the token used as source of the voting weight, the
+        /// quorum numerator (in percentage of the total supply) and the number of blocks of a
+        /// lock period
+        ///
+        /// Note: The governor must be the locker of the token to accept votes with conviction.
+        #[ink(constructor)]
+        pub fn new(
+            token: AccountId,
+            quorum_numerator: u64,
+            lock_period: BlockNumber,
+        ) -> Self {
+            let mut instance = Self::default();
+
+            instance.governor.voting_module._init_token(token);
+            instance
+                .governor
+                .counting_module
+                ._init_conviction(token, lock_period);
+
+            instance
+                ._init_with_quorum_numerator(quorum_numerator)
+                .expect("Should set quorum numerator");
+
+            instance
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests;
+
+#[cfg(all(test, feature = "e2e-tests"))]
+mod e2e_tests;
@@ -0,0 +1,153 @@
+use crate::gov_conviction::*;
+use ink_governance::{
+    governor::*,
+    governor_counting_conviction::*,
+};
+
+use ink_e2e::build_message;
+
+use ink_governance::{
+    governor::governor_external::Governor,
+    governor_counting_conviction::countingconviction_external::CountingConviction,
+    traits::token::psp22::extensions::votes::psp22votes_external::PSP22Votes,
+};
+use openbrush::contracts::psp22::psp22_external::PSP22;
+
+use psp22_votes::psp22_votes::ContractRef as TokenRef;
+
+type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[ink_e2e::test(additional_contracts = "../psp22_votes/Cargo.toml")]
+async fn e2e_can_vote_with_conviction(
+    mut client: ink_e2e::Client<C, E>,
+) -> E2EResult<()> {
+    let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+    let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+    // 1)
+    // Instantiate(Mint) the token with init supply, alice is the locker
+    let token_constructor = TokenRef::new(1000);
+    let token_acc_id = client
+        .instantiate("psp22_votes", &ink_e2e::alice(), token_constructor, 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    // 2)
+    // Instantiate the governor with a quorum of 4% and a lock period of 2 blocks, then make it
+    // the locker of the token
+    let constructor = ContractRef::new(token_acc_id, 4, 2);
+    let contract_acc_id = client
+        .instantiate("gov_conviction", &ink_e2e::alice(), constructor, 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    let set_locker = build_message::<TokenRef>(token_acc_id.clone())
+        .call(|token| token.set_locker(contract_acc_id));
+    client
+        .call(&ink_e2e::alice(), set_locker, 0, None)
+        .await
+        .unwrap();
+
+    // 3)
+    // Propose and cast Vote(For) with the whole supply and a 2x conviction
+    let propose = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.propose(Proposal::default(), String::from("Test proposal")));
+    let proposal_id = client
+        .call_dry_run(&ink_e2e::alice(), &propose, 0, None)
+        .await
+        .return_value()
+        .unwrap();
+    client
+        .call(&ink_e2e::alice(), propose, 0, None)
+        .await
+        .unwrap();
+
+    let for_vote = build_message::<ContractRef>(contract_acc_id.clone()).call(|gov| {
+        gov.cast_vote_with_reason_and_params(proposal_id, 2, String::new(), vec![2])
+    });
+    client
+        .call(&ink_e2e::alice(), for_vote, 0, None)
+        .await
+        .unwrap();
+
+    let proposal_votes = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.proposal_votes(proposal_id));
+    let proposal_votes_res = client
+        .call_dry_run(&ink_e2e::alice(), &proposal_votes, 0, None)
+        .await
+        .return_value()
+        .unwrap();
+    assert_eq!(proposal_votes_res.for_votes, 2000);
+
+    let voter_conviction = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.voter_conviction(proposal_id, alice));
+    let voter_conviction_res = client
+        .call_dry_run(&ink_e2e::alice(), &voter_conviction, 0, None)
+        .await
+        .return_value();
+    assert_eq!(voter_conviction_res, Some(Conviction::Locked2x));
+
+    // 4)
+    // The weight is locked for 2 lock periods and cannot be transferred
+    let locked_balance = build_message::<TokenRef>(token_acc_id.clone())
+        .call(|token| token.locked_balance(alice));
+    let locked_balance_res = client
+        .call_dry_run(&ink_e2e::alice(), &locked_balance, 0, None)
+        .await
+        .return_value();
+    assert_eq!(locked_balance_res, 1000);
+
+    let transfer = build_message::<TokenRef>(token_acc_id.clone())
+        .call(|token| token.transfer(bob, 1, Vec::new()));
+    let transfer_res = client
+        .call_dry_run(&ink_e2e::alice(), &transfer, 0, None)
+        .await
+        .return_value();
+    assert!(transfer_res.is_err());
+
+    // Do extrinsincs to advance the blocks after the lock (instant_finality)
+    // TODO: delete if ink_e2e update
+    for _ in 0..4 {
+        let proposal_state = build_message::<ContractRef>(contract_acc_id.clone())
+            .call(|gov| gov.state(proposal_id));
+        client
+            .call(&ink_e2e::alice(), proposal_state, 0, None)
+            .await
+            .unwrap();
+    }
+
+    let proposal_state = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.state(proposal_id));
+    let proposal_state_res = client
+        .call_dry_run(&ink_e2e::alice(), &proposal_state, 0, None)
+        .await
+        .return_value()
+        .unwrap();
+    assert_eq!(proposal_state_res, ProposalState::Succeeded);
+
+    // 5)
+    // Once the lock is expired it can be removed and the tokens transferred
+    let unlock =
+        build_message::<TokenRef>(token_acc_id.clone()).call(|token| token.unlock(alice));
+    client.call(&ink_e2e::bob(), unlock, 0, None).await.unwrap();
+
+    let locked_balance = build_message::<TokenRef>(token_acc_id.clone())
+        .call(|token| token.locked_balance(alice));
+    let locked_balance_res = client
+        .call_dry_run(&ink_e2e::alice(), &locked_balance, 0, None)
+        .await
+        .return_value();
+    assert_eq!(locked_balance_res, 0);
+
+    let transfer = build_message::<TokenRef>(token_acc_id.clone())
+        .call(|token| token.transfer(bob, 1, Vec::new()));
+    let transfer_res = client
+        .call_dry_run(&ink_e2e::alice(), &transfer, 0, None)
+        .await
+        .return_value();
+    assert_eq!(transfer_res, Ok(()));
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use ink::env::{
+    test::DefaultAccounts,
+    DefaultEnvironment,
+};
+
+use crate::gov_conviction::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::AccountId,
+};
+
+use ink_governance::{
+    governor_counting_conviction::*,
+    governor_votes::*,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+#[ink::test]
+/// The constructor does its job
+fn contruction_works() {
+    let accounts = default_accounts();
+
+    set_caller(accounts.alice);
+    let contract = Contract::new(accounts.django, 4, 10);
+
+    assert_eq!(contract.token(), accounts.django);
+    assert_eq!(contract.conviction_token(), accounts.django);
+    assert_eq!(contract.lock_period(), 10);
+}
+
+#[ink::test]
+/// The conviction multiplies the weight and doubles the lock periods at every level
+fn conviction_works() {
+    assert_eq!(Conviction::try_from(0).unwrap(), Conviction::None);
+    assert_eq!(Conviction::try_from(6).unwrap(), Conviction::Locked6x);
+    assert_eq!(
+        Conviction::try_from(7).unwrap_err(),
+        CountingError::InvalidParams
+    );
+
+    assert_eq!(Conviction::None.votes(1000), Some(100));
+    assert_eq!(Conviction::Locked1x.votes(1000), Some(1000));
+    assert_eq!(Conviction::Locked6x.votes(1000), Some(6000));
+    assert_eq!(Conviction::Locked2x.votes(u64::MAX), None);
+
+    assert_eq!(Conviction::None.lock_periods(), 0);
+    assert_eq!(Conviction::Locked1x.lock_periods(), 1);
+    assert_eq!(Conviction::Locked3x.lock_periods(), 4);
+    assert_eq!(Conviction::Locked6x.lock_periods(), 32);
+}
@@ -0,0 +1,297 @@
+use ink::env::{
+    test::DefaultAccounts,
+    DefaultEnvironment,
+};
+
+use crate::gov_fractional::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_counting_fractional::*,
+    governor_voting_group::*,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 10,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 1,
+    };
+
+    let init_members = vec![alice_member, bob_member];
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, init_members).unwrap()
+}
+
+fn propose(contract: &mut Contract) -> ProposalId {
+    let accounts = default_accounts();
+
+    set_caller(accounts.bob);
+    let proposal = Proposal::default();
+    let description = String::from("Test proposal");
+    contract.propose(proposal, description).unwrap()
+}
+
+fn fractional_params(against_votes: u64, for_votes: u64, abstain_votes: u64) -> Vec<u8> {
+    scale::Encode::encode(&ProposalVote {
+        against_votes,
+        for_votes,
+        abstain_votes,
+    })
+}
+
+#[ink::test]
+/// A vote without params assigns the whole weight to the support
+fn nominal_vote_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let response = contract.cast_vote(proposal_id, 2).unwrap();
+    assert_eq!(response, 10);
+
+    let proposal_votes = ProposalVote {
+        against_votes: 0,
+        for_votes: 10,
+        abstain_votes: 0,
+    };
+    assert_eq!(
+        contract.proposal_votes(proposal_id).unwrap(),
+        proposal_votes
+    );
+    assert_eq!(contract.used_weight(proposal_id, accounts.alice), 10);
+    assert!(contract.has_voted(proposal_id, accounts.alice));
+    assert!(!contract.has_voted(proposal_id, accounts.bob));
+}
+
+#[ink::test]
+/// A vote with params splits the weight between the vote types
+fn fractional_vote_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            fractional_params(2, 3, 1),
+        )
+        .unwrap();
+    assert_eq!(contract.used_weight(proposal_id, accounts.alice), 6);
+
+    // The account can vote again until its whole weight is used
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            fractional_params(0, 4, 0),
+        )
+        .unwrap();
+    assert_eq!(contract.used_weight(proposal_id, accounts.alice), 10);
+
+    let proposal_votes = ProposalVote {
+        against_votes: 2,
+        for_votes: 7,
+        abstain_votes: 1,
+    };
+    assert_eq!(
+        contract.proposal_votes(proposal_id).unwrap(),
+        proposal_votes
+    );
+}
+
+#[ink::test]
+/// The votes cast by an account cannot exceed its weight
+fn fractional_vote_exceeding_weight_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            fractional_params(5, 5, 0),
+        )
+        .unwrap();
+
+    let err_response = contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            fractional_params(0, 1, 0),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VoteWeightExceeded"))
+    );
+}
+
+#[ink::test]
+/// A nominal vote is not allowed after a fractional vote
+fn nominal_vote_after_fractional_vote_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            fractional_params(1, 0, 0),
+        )
+        .unwrap();
+
+    let err_response = contract.cast_vote(proposal_id, 2).unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VoteAlreadyCast"))
+    );
+}
+
+#[ink::test]
+/// A fractional vote must cast some votes
+fn empty_fractional_vote_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            fractional_params(0, 0, 0),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::EmptyVote"))
+    );
+    assert!(!contract.has_voted(proposal_id, accounts.alice));
+}
+
+#[ink::test]
+/// The votes of a proposal cannot overflow
+fn votes_overflow_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: u64::MAX,
+    };
+    contract.update_members(vec![bob_member], vec![]).unwrap();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 1).unwrap();
+    set_caller(accounts.bob);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            fractional_params(0, u64::MAX, 0),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VotesOverflow"))
+    );
+    assert!(!contract.has_voted(proposal_id, accounts.bob));
+}
+
+#[ink::test]
+/// Invalid params are rejected
+fn invalid_params_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(proposal_id, 0, String::from(""), vec![1, 2, 3])
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::InvalidParams"))
+    );
+}
+
+#[ink::test]
+/// A proposal without votes is defeated once the voting period is over
+fn proposal_without_votes_is_defeated() {
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
+
+#[ink::test]
+/// A proposal with as many for votes as against votes is defeated
+fn tied_proposal_is_defeated() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            fractional_params(5, 5, 0),
+        )
+        .unwrap();
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
@@ -89,6 +89,40 @@ pub mod gov_group {
         pub params: Vec<u8>,
     }
 
+    /// Emitted when a vote replaces the previous vote of the voter
+    #[ink(event)]
+    pub struct VoteChanged {
+        /// The account who changed the vote of the proposal.
+        #[ink(topic)]
+        pub voter: AccountId,
+        /// The id of the proposal.
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        /// The vote type replaced.
+        pub old_support: u8,
+        /// The weight of the vote replaced.
+        pub old_weight: u64,
+        /// The new vote type.
+        pub new_support: u8,
+        /// The weight of the new vote.
+        pub new_weight: u64,
+    }
+
+    /// Emitted when a vote is retracted
+    #[ink(event)]
+    pub struct VoteRetracted {
+        /// The account who retracted the vote of the proposal.
+        #[ink(topic)]
+        pub voter: AccountId,
+        /// The id of the proposal.
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        /// The vote type retracted.
+        pub support: u8,
+        /// The weight of the vote retracted.
+        pub weight: u64,
+    }
+
     #[ink(storage)]
     #[derive(Default, Storage)]
     pub struct Contract {
@@ -165,12 +199,56 @@ pub mod gov_group {
                 params,
             })
         }
+        fn _emit_vote_changed(
+            &self,
+            voter: AccountId,
+            proposal_id: ProposalId,
+            old_support: u8,
+            old_weight: u64,
+            new_support: u8,
+            new_weight: u64,
+        ) {
+            self.env().emit_event(VoteChanged {
+                voter,
+                proposal_id,
+                old_support,
+                old_weight,
+                new_support,
+                new_weight,
+            })
+        }
+        fn _emit_vote_retracted(
+            &self,
+            voter: AccountId,
+            proposal_id: ProposalId,
+            support: u8,
+            weight: u64,
+        ) {
+            self.env().emit_event(VoteRetracted {
+                voter,
+                proposal_id,
+                support,
+                weight,
+            })
+        }
         fn _emit_proposal_canceled(&self, proposal_id: ProposalId) {
             self.env().emit_event(ProposalCanceled { proposal_id })
         }
         fn _emit_proposal_executed(&self, proposal_id: ProposalId) {
             self.env().emit_event(ProposalExecuted { proposal_id })
         }
+        fn _check_call_output(
+            &self,
+            transaction: &Transaction,
+            output: &CallOutput,
+        ) -> Result<(), GovernorError> {
+            // `update_members` returns `Result<(), VotingGroupError>`
+            if transaction.selector == ink::selector_bytes!("VotingGroup::update_members")
+            {
+                output.decode_result::<(), VotingGroupError>()?;
+            }
+            Ok(())
+        }
     }
 
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -375,10 +375,12 @@ async fn e2e_can_propose_vote_execute(
     let selector = <[u8; 4]>::from_hex(selector_hex).expect("Decoding failed");
 
     let proposal = Proposal {
-        callee: contract_acc_id,
-        selector,
-        input,
-        transferred_value: 0,
+        transactions: vec![Transaction {
+            callee: contract_acc_id,
+            selector,
+            input,
+            transferred_value: 0,
+        }],
     };
     let description = String::from("Test proposal");
     let description_hash = Hash::try_from(blake2x256!("Test proposal")).unwrap();
@@ -459,3 +461,136 @@ async fn e2e_can_propose_vote_execute(
 
     Ok(())
 }
+
+// Test to cover the output of the calls executed by a proposal:
+// 1) A proposal that calls a function returning `true` is executed
+// 2) A proposal that calls a function returning `Err` is reverted with the encoded error
+#[ink_e2e::test]
+async fn e2e_execute_checks_call_output(
+    mut client: ink_e2e::Client<C, E>,
+) -> E2EResult<()> {
+    let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+    let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+    let alice_member = VotingMember {
+        account: alice,
+        voting_power: 1,
+    };
+    let bob_member = VotingMember {
+        account: bob,
+        voting_power: 1,
+    };
+    let init_members = vec![alice_member, bob_member];
+    let constructor = ContractRef::new(None, init_members);
+    let contract_acc_id = client
+        .instantiate("gov_group", &ink_e2e::alice(), constructor, 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    // The first proposal calls `update_members` without members, that returns
+    // `Err(ZeroMembers)`
+    let mut input = scale::Encode::encode(&Vec::<VotingMember>::new());
+    input.append(&mut scale::Encode::encode(&Vec::<
+        openbrush::traits::AccountId,
+    >::new()));
+    let failing_proposal = Proposal {
+        transactions: vec![Transaction {
+            callee: contract_acc_id,
+            selector: ink::selector_bytes!("VotingGroup::update_members"),
+            input,
+            transferred_value: 0,
+        }],
+    };
+    let failing_description = String::from("Failing proposal");
+    let failing_description_hash =
+        Hash::try_from(blake2x256!("Failing proposal")).unwrap();
+
+    let propose = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.propose(failing_proposal.clone(), failing_description.clone()));
+    let failing_proposal_id = client
+        .call_dry_run(&ink_e2e::bob(), &propose, 0, None)
+        .await
+        .return_value()
+        .unwrap();
+    client
+        .call(&ink_e2e::bob(), propose, 0, None)
+        .await
+        .unwrap();
+
+    let for_vote = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.cast_vote(failing_proposal_id, 2));
+    client
+        .call(&ink_e2e::alice(), for_vote, 0, None)
+        .await
+        .unwrap();
+
+    // The second proposal calls `has_voted`, that returns `true`
+    let succeeding_proposal = Proposal {
+        transactions: vec![Transaction {
+            callee: contract_acc_id,
+            selector: ink::selector_bytes!("CountingSimple::has_voted"),
+            input: scale::Encode::encode(&(failing_proposal_id, alice)),
+            transferred_value: 0,
+        }],
+    };
+    let succeeding_description = String::from("Succeeding proposal");
+    let succeeding_description_hash =
+        Hash::try_from(blake2x256!("Succeeding proposal")).unwrap();
+
+    let propose = build_message::<ContractRef>(contract_acc_id.clone()).call(|gov| {
+        gov.propose(succeeding_proposal.clone(), succeeding_description.clone())
+    });
+    let succeeding_proposal_id = client
+        .call_dry_run(&ink_e2e::bob(), &propose, 0, None)
+        .await
+        .return_value()
+        .unwrap();
+    client
+        .call(&ink_e2e::bob(), propose, 0, None)
+        .await
+        .unwrap();
+
+    let for_vote = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.cast_vote(succeeding_proposal_id, 2));
+    client
+        .call(&ink_e2e::alice(), for_vote, 0, None)
+        .await
+        .unwrap();
+
+    // Do two extrinsincs to advance the block (instant_finality)
+    // TODO: delete if ink_e2e update
+    for _ in 0..2 {
+        let proposal_state = build_message::<ContractRef>(contract_acc_id.clone())
+            .call(|gov| gov.state(succeeding_proposal_id));
+        client
+            .call(&ink_e2e::bob(), proposal_state, 0, None)
+            .await
+            .unwrap();
+    }
+
+    // The call that returns `true` is not reverted
+    let execute = build_message::<ContractRef>(contract_acc_id.clone()).call(|gov| {
+        gov.execute(succeeding_proposal.clone(), succeeding_description_hash)
+    });
+    client
+        .call(&ink_e2e::alice(), execute, 0, None)
+        .await
+        .unwrap();
+
+    // The call that returns `Err` reverts the execution with the encoded error
+    let execute = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.execute(failing_proposal.clone(), failing_description_hash));
+    let execute_res = client
+        .call_dry_run(&ink_e2e::alice(), &execute, 0, None)
+        .await
+        .return_value();
+    assert_eq!(
+        execute_res,
+        Err(GovernorError::CallReverted(scale::Encode::encode(
+            &VotingGroupError::ZeroMembers
+        )))
+    );
+
+    Ok(())
+}
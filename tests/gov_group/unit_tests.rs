@@ -19,10 +19,18 @@ use openbrush::{
     },
     traits::{
         AccountId,
+        Balance,
         Hash,
+        Storage,
         String,
     },
 };
+use secp256k1::{
+    Message,
+    PublicKey,
+    Secp256k1,
+    SecretKey,
+};
 
 use ink_governance::{
     governor::*,
@@ -204,6 +212,64 @@ fn propose_works() {
     assert_eq!(response, proposal_state);
 }
 
+#[ink::test]
+/// Propose a proposal with multiple transactions works correctly
+fn propose_multiple_transactions_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    set_caller(accounts.bob);
+    let err_response = contract
+        .propose(Proposal::default(), String::from(""))
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::EmptyProposal);
+
+    let first = Transaction {
+        callee: accounts.django,
+        selector: [1, 2, 3, 4],
+        ..Default::default()
+    };
+    let second = Transaction {
+        callee: accounts.eve,
+        selector: [5, 6, 7, 8],
+        transferred_value: 10,
+        ..Default::default()
+    };
+    let proposal = Proposal {
+        transactions: vec![first.clone(), second.clone()],
+    };
+    assert_eq!(proposal.transferred_value(), Ok(10));
+
+    let overflowing = Proposal {
+        transactions: vec![
+            Transaction {
+                transferred_value: Balance::MAX,
+                ..Default::default()
+            },
+            second.clone(),
+        ],
+    };
+    assert_eq!(
+        overflowing.transferred_value(),
+        Err(GovernorError::TransferredValueOverflow)
+    );
+
+    // The order of the transactions is part of the proposal id
+    let swapped = Proposal {
+        transactions: vec![second, first],
+    };
+    let description_hash = Hash::default();
+    assert_ne!(
+        contract.hash_proposal(proposal.clone(), description_hash),
+        contract.hash_proposal(swapped, description_hash)
+    );
+
+    let description = String::from("Test proposal");
+    let proposal_id = contract.propose(proposal, description).unwrap();
+    let response = contract.state(proposal_id).unwrap();
+    assert_eq!(response, ProposalState::Active);
+}
+
 #[ink::test]
 /// Cast vote works correctly
 fn cast_vote_works() {
@@ -277,6 +343,14 @@ fn proposal_threshold_works() {
     assert_eq!(response, 0);
 }
 
+#[ink::test]
+fn quorum_works() {
+    let contract = build_contract();
+    // The quorum is provided by the voting group module
+    let response = contract.quorum(0).unwrap();
+    assert_eq!(response, 1);
+}
+
 #[ink::test]
 fn execute_works() {
     let mut contract = build_contract();
@@ -303,3 +377,686 @@ fn execute_works() {
 
     // TODO: update this test if ink-test will support contract deployment.
 }
+
+#[ink::test]
+fn cancel_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal = Proposal::default();
+    let description = String::from("Test proposal");
+    let description_hash = Hash::try_from(
+        contract
+            .env()
+            .hash_bytes::<Blake2x256>(&description)
+            .as_ref(),
+    )
+    .unwrap();
+    let proposal_id = propose(&mut contract);
+
+    // Charlie is neither the proposer nor the guardian.
+    set_caller(accounts.charlie);
+    let err_response = contract
+        .cancel(proposal.clone(), description_hash)
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::OnlyProposerOrGuardian);
+
+    // The proposer can cancel only a pending proposal.
+    set_caller(accounts.bob);
+    let err_response = contract
+        .cancel(proposal.clone(), description_hash)
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotPending);
+
+    // In this case since we are in an off-chain envoriment, the modifier only_governance is not applied
+    // and so we can set the guardian without a passed proposal.
+    set_caller(accounts.alice);
+    contract.set_guardian(Some(accounts.charlie)).unwrap();
+    assert_eq!(contract.guardian(), Some(accounts.charlie));
+
+    // The guardian can cancel the proposal at any time before its execution.
+    set_caller(accounts.charlie);
+    let response = contract.cancel(proposal.clone(), description_hash).unwrap();
+    assert_eq!(response, proposal_id);
+
+    let response = contract.state(proposal_id).unwrap();
+    assert_eq!(response, ProposalState::Canceled);
+
+    let emittend_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let decoded_events = decode_events(emittend_events);
+    if let Event::ProposalCanceled(ProposalCanceled {
+        proposal_id: prop_id,
+    }) = decoded_events.last().unwrap()
+    {
+        assert_eq!(prop_id, &proposal_id);
+    } else {
+        panic!("encountered unexpected event kind: expected a ProposalCanceled event")
+    }
+
+    let err_response = contract.cancel(proposal, description_hash).unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotActive);
+}
+
+#[ink::test]
+/// Cast vote by signature works correctly
+fn cast_vote_by_sig_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    // The voter is the account of an ecdsa key pair
+    let secp = Secp256k1::new();
+    let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+    let public_key = PublicKey::from_secret_key(&secp, &secret_key).serialize();
+    let mut voter = [0; 32];
+    ink::env::hash_bytes::<Blake2x256>(&public_key, &mut voter);
+    let voter = AccountId::from(voter);
+
+    set_caller(accounts.alice);
+    contract
+        .update_members(
+            vec![VotingMember {
+                account: voter,
+                voting_power: 1,
+            }],
+            vec![],
+        )
+        .unwrap();
+
+    let proposal_id = propose(&mut contract);
+    assert_eq!(contract.nonces(voter), 0);
+
+    let sign = |contract: &Contract, support: u8, nonce: u64| {
+        let ballot = Ballot {
+            proposal_id,
+            support,
+            reason: String::from(""),
+            params: Vec::new(),
+            nonce,
+        };
+        let message = Message::from_slice(&contract._hash_ballot(&ballot)).unwrap();
+        let (recovery_id, compact) = secp
+            .sign_ecdsa_recoverable(&message, &secret_key)
+            .serialize_compact();
+        let mut signature = [0; 65];
+        signature[..64].copy_from_slice(&compact);
+        signature[64] = recovery_id.to_i32() as u8;
+        Signature::Ecdsa(signature)
+    };
+
+    // The signature must be produced by the voter
+    set_caller(accounts.charlie);
+    let err_response = contract
+        .cast_vote_by_sig(proposal_id, 2, accounts.bob, sign(&contract, 2, 0))
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::InvalidSignature);
+
+    // The signature must match the ballot
+    let err_response = contract
+        .cast_vote_by_sig(proposal_id, 1, voter, sign(&contract, 2, 0))
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::InvalidSignature);
+
+    // Anyone can relay the vote of the voter
+    let signature = sign(&contract, 2, contract.nonces(voter));
+    let response = contract
+        .cast_vote_by_sig(proposal_id, 2, voter, signature.clone())
+        .unwrap();
+    assert_eq!(response, 1);
+    assert!(contract.has_voted(proposal_id, voter));
+    assert_eq!(contract.nonces(voter), 1);
+
+    let emittend_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let decoded_events = decode_events(emittend_events);
+    if let Event::VoteCasted(VoteCasted {
+        voter: event_voter,
+        proposal_id: prop_id,
+        support,
+        ..
+    }) = decoded_events.last().unwrap()
+    {
+        assert_eq!(event_voter, &voter);
+        assert_eq!(prop_id, &proposal_id);
+        assert_eq!(support, &2);
+    } else {
+        panic!("encountered unexpected event kind: expected a VoteCasted event")
+    }
+
+    // The same signature cannot be replayed
+    let err_response = contract
+        .cast_vote_by_sig(proposal_id, 2, voter, signature)
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::InvalidSignature);
+}
+
+#[ink::test]
+fn relay_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    // Only the governance can relay a call.
+    set_caller(accounts.bob);
+    let err_response = contract.relay(Transaction::default()).unwrap_err();
+    assert_eq!(err_response, GovernorError::OnlyGovernance);
+
+    // In this case since we are in an off-chain envoriment we can't test a successfull
+    // relay.(cross-contract calls are not supported)
+}
+
+#[ink::test]
+/// The output of a call is decoded with the return type of the called function
+fn check_call_output_works() {
+    let contract = build_contract();
+    let update_members = Transaction {
+        selector: ink::selector_bytes!("VotingGroup::update_members"),
+        ..Default::default()
+    };
+
+    // A function that returns `true` is not reverted
+    let output = CallOutput(scale::Encode::encode(&true));
+    assert_eq!(
+        governor::Internal::_check_call_output(
+            &contract,
+            &Transaction::default(),
+            &output
+        ),
+        Ok(())
+    );
+
+    let output = CallOutput(scale::Encode::encode(&Ok::<(), VotingGroupError>(())));
+    assert_eq!(
+        governor::Internal::_check_call_output(&contract, &update_members, &output),
+        Ok(())
+    );
+
+    // An `Err` returned by the function is propagated with the encoded error
+    let output = CallOutput(scale::Encode::encode(&Err::<(), VotingGroupError>(
+        VotingGroupError::ZeroMembers,
+    )));
+    assert_eq!(
+        governor::Internal::_check_call_output(&contract, &update_members, &output),
+        Err(GovernorError::CallReverted(scale::Encode::encode(
+            &VotingGroupError::ZeroMembers
+        )))
+    );
+}
+
+#[ink::test]
+/// Without the return type of the called function, the output must be `()` or a `Result`
+fn check_result_works() {
+    assert_eq!(CallOutput(Vec::new()).check_result(), Ok(()));
+    assert_eq!(
+        CallOutput(scale::Encode::encode(&Ok::<u64, VotingGroupError>(1))).check_result(),
+        Ok(())
+    );
+    assert_eq!(
+        CallOutput(scale::Encode::encode(&Err::<(), VotingGroupError>(
+            VotingGroupError::ZeroMembers,
+        )))
+        .check_result(),
+        Err(GovernorError::CallReverted(scale::Encode::encode(
+            &VotingGroupError::ZeroMembers
+        )))
+    );
+
+    // An output that is not a `Result` is rejected
+    assert_eq!(
+        CallOutput(scale::Encode::encode(&2u8)).check_result(),
+        Err(GovernorError::CallRevertedWithoutMessage)
+    );
+}
+
+#[ink::test]
+/// The class of a transaction can be updated only through governance
+fn set_transaction_class_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    assert_eq!(contract.transaction_class(accounts.django, [1, 2, 3, 4]), 0);
+
+    set_caller(accounts.bob);
+    let err_response = contract
+        .set_transaction_class(accounts.django, [1, 2, 3, 4], 1)
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::OnlyGovernance);
+
+    // In this case since we are in an off-chain envoriment, the contract is called by alice
+    // and so the modifier only_governance is satisfied.
+    set_caller(accounts.alice);
+    contract
+        .set_transaction_class(accounts.django, [1, 2, 3, 4], 1)
+        .unwrap();
+    assert_eq!(contract.transaction_class(accounts.django, [1, 2, 3, 4]), 1);
+    assert_eq!(contract.transaction_class(accounts.django, [5, 6, 7, 8]), 0);
+    assert_eq!(contract.transaction_class(accounts.eve, [1, 2, 3, 4]), 0);
+
+    contract
+        .set_transaction_class(accounts.django, [1, 2, 3, 4], 0)
+        .unwrap();
+    assert_eq!(contract.transaction_class(accounts.django, [1, 2, 3, 4]), 0);
+}
+
+#[ink::test]
+/// The success threshold of a class can be updated only through governance
+fn set_class_success_threshold_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let two_thirds = SuccessThreshold {
+        numerator: 2,
+        denominator: 3,
+    };
+
+    assert_eq!(
+        contract.class_success_threshold(1),
+        SuccessThreshold::default()
+    );
+
+    set_caller(accounts.bob);
+    let err_response = contract
+        .set_class_success_threshold(1, two_thirds)
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::OnlyGovernance);
+
+    set_caller(accounts.alice);
+    for threshold in [(1, 3), (4, 3), (0, 0)] {
+        let err_response = contract
+            .set_class_success_threshold(
+                1,
+                SuccessThreshold {
+                    numerator: threshold.0,
+                    denominator: threshold.1,
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err_response, GovernorError::InvalidSuccessThreshold);
+    }
+
+    contract.set_class_success_threshold(1, two_thirds).unwrap();
+    assert_eq!(contract.class_success_threshold(1), two_thirds);
+}
+
+#[ink::test]
+/// The proposals keep the threshold of their class at the time of creation
+fn success_threshold_is_stored_at_creation() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let two_thirds = SuccessThreshold {
+        numerator: 2,
+        denominator: 3,
+    };
+    let transaction = Transaction {
+        callee: accounts.django,
+        selector: [1, 2, 3, 4],
+        ..Default::default()
+    };
+
+    contract.set_class_success_threshold(0, two_thirds).unwrap();
+    contract.set_class_success_threshold(1, two_thirds).unwrap();
+    contract
+        .set_transaction_class(accounts.django, [1, 2, 3, 4], 1)
+        .unwrap();
+
+    set_caller(accounts.bob);
+    let empty_proposal = contract
+        .propose(Proposal::default(), String::from("Empty proposal"))
+        .unwrap();
+    let class_proposal = contract
+        .propose(
+            Proposal {
+                transactions: vec![transaction],
+            },
+            String::from("Class proposal"),
+        )
+        .unwrap();
+
+    set_caller(accounts.alice);
+    contract
+        .set_class_success_threshold(0, SuccessThreshold::default())
+        .unwrap();
+    contract
+        .set_class_success_threshold(1, SuccessThreshold::default())
+        .unwrap();
+    contract
+        .set_transaction_class(accounts.django, [1, 2, 3, 4], 0)
+        .unwrap();
+    assert_eq!(contract.success_threshold(empty_proposal), two_thirds);
+    assert_eq!(contract.success_threshold(class_proposal), two_thirds);
+}
+
+#[ink::test]
+/// A proposal is successful only if its for votes reach the strictest threshold of the classes
+/// of its transactions
+fn supermajority_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let two_thirds = SuccessThreshold {
+        numerator: 2,
+        denominator: 3,
+    };
+    let three_quarters = SuccessThreshold {
+        numerator: 3,
+        denominator: 4,
+    };
+    let first = Transaction {
+        callee: accounts.django,
+        selector: [1, 2, 3, 4],
+        ..Default::default()
+    };
+    let second = Transaction {
+        callee: accounts.django,
+        selector: [5, 6, 7, 8],
+        ..Default::default()
+    };
+
+    let charlie_member = VotingMember {
+        account: accounts.charlie,
+        voting_power: 1,
+    };
+    contract
+        .update_members(vec![charlie_member], vec![])
+        .unwrap();
+
+    contract.set_class_success_threshold(1, two_thirds).unwrap();
+    contract
+        .set_class_success_threshold(2, three_quarters)
+        .unwrap();
+    contract
+        .set_transaction_class(accounts.django, [1, 2, 3, 4], 1)
+        .unwrap();
+    contract
+        .set_transaction_class(accounts.django, [5, 6, 7, 8], 2)
+        .unwrap();
+
+    set_caller(accounts.bob);
+    let two_thirds_proposal = contract
+        .propose(
+            Proposal {
+                transactions: vec![first.clone()],
+            },
+            String::from("Two thirds"),
+        )
+        .unwrap();
+    let three_quarters_proposal = contract
+        .propose(
+            Proposal {
+                transactions: vec![second.clone()],
+            },
+            String::from("Three quarters"),
+        )
+        .unwrap();
+    let mixed_proposal = contract
+        .propose(
+            Proposal {
+                transactions: vec![first, second],
+            },
+            String::from("Mixed"),
+        )
+        .unwrap();
+    assert_eq!(contract.success_threshold(two_thirds_proposal), two_thirds);
+    assert_eq!(
+        contract.success_threshold(three_quarters_proposal),
+        three_quarters
+    );
+    assert_eq!(contract.success_threshold(mixed_proposal), three_quarters);
+
+    for proposal_id in [two_thirds_proposal, three_quarters_proposal, mixed_proposal] {
+        set_caller(accounts.alice);
+        contract.cast_vote(proposal_id, 2).unwrap();
+        set_caller(accounts.bob);
+        contract.cast_vote(proposal_id, 2).unwrap();
+        set_caller(accounts.charlie);
+        contract.cast_vote(proposal_id, 1).unwrap();
+    }
+
+    // The voting period is over
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(two_thirds_proposal).unwrap(),
+        ProposalState::Succeeded
+    );
+    assert_eq!(
+        contract.state(three_quarters_proposal).unwrap(),
+        ProposalState::Defeated
+    );
+    assert_eq!(
+        contract.state(mixed_proposal).unwrap(),
+        ProposalState::Defeated
+    );
+}
+
+#[ink::test]
+/// Only the governance can enable vote changing
+fn set_vote_changing_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    assert!(!contract.vote_changing());
+
+    set_caller(accounts.bob);
+    let err_response = contract.set_vote_changing(true).unwrap_err();
+    assert_eq!(err_response, GovernorError::OnlyGovernance);
+
+    set_caller(accounts.alice);
+    contract.set_vote_changing(true).unwrap();
+    assert!(contract.vote_changing());
+}
+
+#[ink::test]
+/// A vote is final unless vote changing is enabled
+fn vote_twice_fails() {
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+    cast_against_vote(&mut contract, proposal_id);
+
+    let err_response = contract.cast_vote(proposal_id, 2).unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VoteAlreadyCast"))
+    );
+}
+
+#[ink::test]
+/// A new vote replaces the previous one when vote changing is enabled
+fn change_vote_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    set_caller(accounts.alice);
+    contract.set_vote_changing(true).unwrap();
+
+    let proposal_id = propose(&mut contract);
+    cast_against_vote(&mut contract, proposal_id);
+    contract.cast_vote(proposal_id, 2).unwrap();
+
+    let proposal_votes = ProposalVote {
+        against_votes: 0,
+        for_votes: 1,
+        abstain_votes: 0,
+    };
+    let response = contract.proposal_votes(proposal_id).unwrap();
+    assert_eq!(response, proposal_votes);
+    assert!(contract.has_voted(proposal_id, accounts.bob));
+
+    let emittend_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let decoded_events = decode_events(emittend_events);
+    if let Event::VoteChanged(VoteChanged {
+        voter,
+        proposal_id: prop_id,
+        old_support,
+        old_weight,
+        new_support,
+        new_weight,
+    }) = &decoded_events[decoded_events.len() - 2]
+    {
+        assert_eq!(*voter, accounts.bob);
+        assert_eq!(*prop_id, proposal_id);
+        assert_eq!(*old_support, 1);
+        assert_eq!(*old_weight, 1);
+        assert_eq!(*new_support, 2);
+        assert_eq!(*new_weight, 1);
+    } else {
+        panic!("encountered unexpected event kind: expected a VoteChanged event")
+    }
+}
+
+#[ink::test]
+/// The votes of a proposal cannot overflow
+fn votes_overflow_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let charlie_member = VotingMember {
+        account: accounts.charlie,
+        voting_power: u64::MAX,
+    };
+    contract
+        .update_members(vec![charlie_member], vec![])
+        .unwrap();
+    let proposal_id = propose(&mut contract);
+
+    contract.cast_vote(proposal_id, 2).unwrap();
+    set_caller(accounts.charlie);
+    let err_response = contract.cast_vote(proposal_id, 2).unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VotesOverflow"))
+    );
+    assert!(!contract.has_voted(proposal_id, accounts.charlie));
+}
+
+#[ink::test]
+/// A vote stored before the receipts cannot be cast again
+fn vote_without_receipt_is_final() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    set_caller(accounts.alice);
+    contract.set_vote_changing(true).unwrap();
+    let proposal_id = propose(&mut contract);
+
+    Storage::<
+        governor::Data<governor_counting_simple::Counting, governor_voting_group::Voting>,
+    >::data(&mut contract)
+    .counting_module
+    .has_voted
+    .insert(&(accounts.bob, proposal_id), &true);
+    assert!(contract.has_voted(proposal_id, accounts.bob));
+    assert_eq!(contract.get_receipt(proposal_id, accounts.bob), None);
+
+    let err_response = contract.cast_vote(proposal_id, 2).unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VoteAlreadyCast"))
+    );
+}
+
+#[ink::test]
+/// A vote can be retracted only when vote changing is enabled
+fn retract_vote_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+    cast_against_vote(&mut contract, proposal_id);
+
+    let err_response = contract.retract_vote(proposal_id).unwrap_err();
+    assert_eq!(err_response, GovernorError::VoteChangingDisabled);
+
+    set_caller(accounts.alice);
+    contract.set_vote_changing(true).unwrap();
+    let err_response = contract.retract_vote(proposal_id).unwrap_err();
+    assert_eq!(err_response, GovernorError::VoteNotCast);
+
+    set_caller(accounts.bob);
+    contract.retract_vote(proposal_id).unwrap();
+    assert!(!contract.has_voted(proposal_id, accounts.bob));
+    assert_eq!(contract.get_receipt(proposal_id, accounts.bob), None);
+    assert_eq!(
+        contract.proposal_votes(proposal_id).unwrap(),
+        ProposalVote::default()
+    );
+
+    let emittend_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let decoded_events = decode_events(emittend_events);
+    if let Event::VoteRetracted(VoteRetracted {
+        voter,
+        proposal_id: prop_id,
+        support,
+        weight,
+    }) = &decoded_events[decoded_events.len() - 1]
+    {
+        assert_eq!(*voter, accounts.bob);
+        assert_eq!(*prop_id, proposal_id);
+        assert_eq!(*support, 1);
+        assert_eq!(*weight, 1);
+    } else {
+        panic!("encountered unexpected event kind: expected a VoteRetracted event")
+    }
+
+    // A new vote keeps the position of the first vote
+    contract.cast_vote(proposal_id, 2).unwrap();
+    assert!(contract.has_voted(proposal_id, accounts.bob));
+    assert_eq!(contract.voter_count(proposal_id), 1);
+
+    // The voting period is over
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    let err_response = contract.retract_vote(proposal_id).unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotActive);
+}
+
+#[ink::test]
+fn get_receipt_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+    assert_eq!(contract.get_receipt(proposal_id, accounts.bob), None);
+
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            2,
+            String::from("Reason"),
+            vec![1, 2],
+        )
+        .unwrap();
+
+    let params_hash = Hash::from(contract.env().hash_bytes::<Blake2x256>(&[1, 2]));
+    let receipt = VoteReceipt {
+        support: 2,
+        weight: 1,
+        params_hash,
+    };
+    assert_eq!(
+        contract.get_receipt(proposal_id, accounts.bob),
+        Some(receipt)
+    );
+    assert_eq!(contract.get_receipt(proposal_id, accounts.alice), None);
+}
+
+#[ink::test]
+/// The voters are listed in order of first vote
+fn voters_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    set_caller(accounts.alice);
+    contract.set_vote_changing(true).unwrap();
+
+    let proposal_id = propose(&mut contract);
+    cast_against_vote(&mut contract, proposal_id);
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 2).unwrap();
+    // A changed vote keeps the position of the voter
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 3).unwrap();
+    assert_eq!(contract.voter_count(proposal_id), 2);
+
+    let voters = contract.voters(proposal_id, 0, 10);
+    assert_eq!(voters.len(), 2);
+    assert_eq!(voters[0].0, accounts.bob);
+    assert_eq!(voters[0].1.support, 3);
+    assert_eq!(voters[1].0, accounts.alice);
+    assert_eq!(voters[1].1.support, 2);
+
+    let voters = contract.voters(proposal_id, 1, 1);
+    assert_eq!(voters.len(), 1);
+    assert_eq!(voters[0].0, accounts.alice);
+
+    assert!(contract.voters(proposal_id, 2, 1).is_empty());
+}
@@ -0,0 +1,286 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+#[openbrush::contract]
+pub mod gov_late_quorum {
+
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::vec::Vec,
+    };
+    use ink_governance::{
+        governor::*,
+        governor_counting_simple::*,
+        governor_prevent_late_quorum::*,
+        governor_voting_group::*,
+    };
+    use openbrush::traits::{
+        Storage,
+        String,
+    };
+
+    /// Emitted when a proposal is create
+    #[ink(event)]
+    pub struct ProposalCreated {
+        /// The account that created the proposal.
+        #[ink(topic)]
+        pub proposer: AccountId,
+        /// The id of the created proposal.
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        /// The proposal created.
+        pub proposal: Proposal,
+        /// The block number when the proposal start.
+        pub start_block: BlockNumber,
+        /// The block number when the proposal end.
+        pub end_block: BlockNumber,
+        /// Description of the proposal
+        pub description: String,
+    }
+
+    /// Emitted when a proposal is cancel
+    #[ink(event)]
+    pub struct ProposalCanceled {
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+    }
+
+    /// Emitted when a proposal is execute
+    #[ink(event)]
+    pub struct ProposalExecuted {
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+    }
+
+    /// Emitted when a vote is cast
+    #[ink(event)]
+    pub struct VoteCasted {
+        /// The account who cast the vote of the proposal.
+        #[ink(topic)]
+        pub voter: AccountId,
+        /// The id of the proposal.
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        /// The vote type casted.
+        pub support: u8,
+        /// The weight of the vote cast.
+        pub weight: u64,
+        /// Reason of the vote.
+        pub reason: String,
+    }
+
+    /// Emitted when a vote is cast with params
+    #[ink(event)]
+    pub struct VoteCastedWithParams {
+        /// The account who cast the vote of the proposal.
+        #[ink(topic)]
+        pub voter: AccountId,
+        /// The id of the proposal.
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        /// The vote type casted.
+        pub support: u8,
+        /// The weight of the vote cast.
+        pub weight: u64,
+        /// Reason of the vote.
+        pub reason: String,
+        /// Params of the vote.
+        pub params: Vec<u8>,
+    }
+
+    /// Emitted when a proposal deadline is pushed back due to reaching quorum late in its voting
+    /// period
+    #[ink(event)]
+    pub struct ProposalExtended {
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        /// The extended deadline of the proposal.
+        pub extended_deadline: BlockNumber,
+    }
+
+    /// Emitted when a new vote extension is set
+    #[ink(event)]
+    pub struct LateQuorumVoteExtensionSet {
+        /// The old vote extension.
+        pub old_vote_extension: BlockNumber,
+        /// The new vote extension.
+        pub new_vote_extension: BlockNumber,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        governor: governor::Data<
+            governor_counting_simple::Counting,
+            governor_voting_group::Voting,
+        >,
+        #[storage_field]
+        late_quorum: governor_prevent_late_quorum::Data,
+    }
+
+    impl Governor for Contract {
+        #[ink(message)]
+        fn proposal_deadline(
+            &self,
+            proposal_id: ProposalId,
+        ) -> Result<BlockNumber, GovernorError> {
+            self._late_quorum_proposal_deadline(&proposal_id)
+        }
+    }
+
+    impl GovernorPreventLateQuorum for Contract {}
+
+    impl VotingGroup for Contract {}
+
+    impl CountingSimple for Contract {}
+
+    // Override the internal methods
+    impl governor::Internal for Contract {
+        fn _voting_delay(&self) -> u32 {
+            0 // block
+        }
+        fn _voting_period(&self) -> u32 {
+            2 // block (for testing purpose)
+        }
+        fn _after_count_vote(
+            &mut self,
+            proposal_id: &ProposalId,
+        ) -> Result<(), GovernorError> {
+            self._prevent_late_quorum(proposal_id)
+        }
+        fn _emit_proposal_created(
+            &self,
+            proposer: AccountId,
+            proposal_id: ProposalId,
+            proposal: Proposal,
+            start_block: BlockNumber,
+            end_block: BlockNumber,
+            description: String,
+        ) {
+            self.env().emit_event(ProposalCreated {
+                proposer,
+                proposal_id,
+                proposal,
+                start_block,
+                end_block,
+                description,
+            })
+        }
+        fn _emit_vote_cast(
+            &self,
+            voter: AccountId,
+            proposal_id: ProposalId,
+            support: u8,
+            weight: u64,
+            reason: String,
+        ) {
+            self.env().emit_event(VoteCasted {
+                voter,
+                proposal_id,
+                support,
+                weight,
+                reason,
+            })
+        }
+        fn _emit_vote_cast_with_params(
+            &self,
+            voter: AccountId,
+            proposal_id: ProposalId,
+            support: u8,
+            weight: u64,
+            reason: String,
+            params: Vec<u8>,
+        ) {
+            self.env().emit_event(VoteCastedWithParams {
+                voter,
+                proposal_id,
+                support,
+                weight,
+                reason,
+                params,
+            })
+        }
+        fn _emit_proposal_canceled(&self, proposal_id: ProposalId) {
+            self.env().emit_event(ProposalCanceled { proposal_id })
+        }
+        fn _emit_proposal_executed(&self, proposal_id: ProposalId) {
+            self.env().emit_event(ProposalExecuted { proposal_id })
+        }
+    }
+
+    impl governor_prevent_late_quorum::Internal for Contract {
+        fn _emit_proposal_extended(
+            &self,
+            proposal_id: ProposalId,
+            extended_deadline: BlockNumber,
+        ) {
+            self.env().emit_event(ProposalExtended {
+                proposal_id,
+                extended_deadline,
+            })
+        }
+        fn _emit_late_quorum_vote_extension_set(
+            &self,
+            old_vote_extension: BlockNumber,
+            new_vote_extension: BlockNumber,
+        ) {
+            self.env().emit_event(LateQuorumVoteExtensionSet {
+                old_vote_extension,
+                new_vote_extension,
+            })
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ContractError {
+        Custom(String),
+        VotingGroupError(VotingGroupError),
+    }
+
+    impl From<VotingGroupError> for ContractError {
+        fn from(voting: VotingGroupError) -> Self {
+            match voting {
+                VotingGroupError::NoMember => {
+                    ContractError::Custom(String::from("VG: NoMember"))
+                }
+                _ => ContractError::Custom(String::from("VG: VotingGroupError")),
+            }
+        }
+    }
+
+    impl Contract {
+        /// Initialize the contract with a list of voting members, optional admin (if not set
+        /// the caller will be the admin by default) and the vote extension
+        #[ink(constructor)]
+        pub fn new(
+            admin: Option<AccountId>,
+            init_members: Vec<VotingMember>,
+            vote_extension: BlockNumber,
+        ) -> Result<Self, ContractError> {
+            let mut instance = Self::default();
+
+            // Assign the admin role to the caller if is not set in the parameters
+            let admin = admin.unwrap_or(Self::env().caller());
+
+            // Initialize the group with the members.
+            //
+            // Note: Only the members of the group can propose or vote a proposal.
+            governor_voting_group::VotingGroup::_init_members(
+                &mut instance,
+                admin,
+                init_members,
+            )?;
+
+            instance._init_with_vote_extension(vote_extension);
+
+            Ok(instance)
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests;
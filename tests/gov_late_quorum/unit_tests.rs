@@ -0,0 +1,144 @@
+use ink::{
+    env::{
+        test::{
+            DefaultAccounts,
+            EmittedEvent,
+        },
+        DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+};
+
+use crate::gov_late_quorum::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_prevent_late_quorum::*,
+    governor_voting_group::*,
+};
+
+type Event = <Contract as ::ink::reflect::ContractEventBase>::Type;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 1,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 1,
+    };
+
+    let init_members = vec![alice_member, bob_member];
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, init_members, 5).unwrap()
+}
+
+fn decode_events(emittend_events: Vec<EmittedEvent>) -> Vec<Event> {
+    emittend_events
+        .into_iter()
+        .map(|event| {
+            <Event as scale::Decode>::decode(&mut &event.data[..]).expect("invalid data")
+        })
+        .collect()
+}
+
+fn propose(contract: &mut Contract, description: &str) -> ProposalId {
+    let accounts = default_accounts();
+
+    set_caller(accounts.bob);
+    let proposal = Proposal::default();
+    contract
+        .propose(proposal, String::from(description))
+        .unwrap()
+}
+
+#[ink::test]
+/// The vote extension can be updated
+fn set_late_quorum_vote_extension_works() {
+    let mut contract = build_contract();
+    assert_eq!(contract.late_quorum_vote_extension(), 5);
+
+    // In this case since we are in an off-chain envoriment, the modifier only_governance is not applied
+    // and so we can set the vote extension without a passed proposal.
+    contract.set_late_quorum_vote_extension(10).unwrap();
+    assert_eq!(contract.late_quorum_vote_extension(), 10);
+}
+
+#[ink::test]
+/// The deadline is extended when the quorum is reached late
+fn late_quorum_extends_deadline() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    let proposal_id = propose(&mut contract, "Test proposal");
+    assert_eq!(contract.proposal_deadline(proposal_id).unwrap(), 2);
+
+    // Reach the quorum in the last block of the voting period
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 2).unwrap();
+
+    assert_eq!(contract.proposal_deadline(proposal_id).unwrap(), 7);
+
+    let emittend_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let decoded_events = decode_events(emittend_events);
+    // The deadline is extended before the vote is emitted
+    if let Event::ProposalExtended(ProposalExtended {
+        proposal_id: prop_id,
+        extended_deadline,
+    }) = &decoded_events[decoded_events.len() - 2]
+    {
+        assert_eq!(prop_id, &proposal_id);
+        assert_eq!(extended_deadline, &7);
+    } else {
+        panic!("encountered unexpected event kind: expected a ProposalExtended event")
+    }
+
+    // The other voters have time to react
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(contract.state(proposal_id).unwrap(), ProposalState::Active);
+
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 1).unwrap();
+}
+
+#[ink::test]
+/// The deadline is not extended when the quorum is reached early
+fn early_quorum_does_not_extend_deadline() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    set_caller(accounts.alice);
+    contract.set_late_quorum_vote_extension(1).unwrap();
+
+    let proposal_id = propose(&mut contract, "Test proposal");
+
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 2).unwrap();
+
+    assert_eq!(contract.proposal_deadline(proposal_id).unwrap(), 2);
+}
@@ -0,0 +1,228 @@
+use ink::env::{
+    test::DefaultAccounts,
+    DefaultEnvironment,
+};
+
+use crate::gov_multiple_choice::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_counting_multiple_choice::*,
+    governor_voting_group::*,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 2,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 1,
+    };
+    let charlie_member = VotingMember {
+        account: accounts.charlie,
+        voting_power: 1,
+    };
+
+    let init_members = vec![alice_member, bob_member, charlie_member];
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, init_members).unwrap()
+}
+
+fn options() -> Vec<String> {
+    vec![
+        String::from("Option A"),
+        String::from("Option B"),
+        String::from("Option C"),
+    ]
+}
+
+fn propose(contract: &mut Contract) -> ProposalId {
+    let accounts = default_accounts();
+
+    set_caller(accounts.bob);
+    let proposal = Proposal::default();
+    let description = String::from("Test proposal");
+    contract
+        .propose_with_options(proposal, description, options())
+        .unwrap()
+}
+
+#[ink::test]
+/// The counting mode describes the option set
+fn counting_mode_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    assert_eq!(
+        contract.counting_mode(),
+        String::from("support=option&quorum=option")
+    );
+
+    let proposal_id = propose(&mut contract);
+    assert_eq!(
+        contract.proposal_counting_mode(proposal_id),
+        String::from("support=option&quorum=option&options=Option A,Option B,Option C")
+    );
+
+    set_caller(accounts.bob);
+    let proposal_id = contract
+        .propose_with_options(
+            Proposal::default(),
+            String::from("Second proposal"),
+            vec![String::from("A,B"), String::from("50%")],
+        )
+        .unwrap();
+    assert_eq!(
+        contract.proposal_counting_mode(proposal_id),
+        String::from("support=option&quorum=option&options=A%2CB,50%25")
+    );
+}
+
+#[ink::test]
+/// Propose with options works correctly
+fn propose_with_options_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    set_caller(accounts.bob);
+    let err_response = contract
+        .propose_with_options(
+            Proposal::default(),
+            String::from("Test proposal"),
+            vec![String::from("Option A")],
+        )
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::InvalidProposalOptions);
+
+    let proposal_id = propose(&mut contract);
+    assert_eq!(contract.proposal_options(proposal_id), options());
+    assert_eq!(contract.option_votes(proposal_id), vec![0, 0, 0]);
+    assert_eq!(contract.winning_option(proposal_id), None);
+}
+
+#[ink::test]
+/// The option with the most votes wins the proposal
+fn winning_option_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 1).unwrap();
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 2).unwrap();
+
+    assert_eq!(contract.option_votes(proposal_id), vec![0, 2, 1]);
+    assert_eq!(contract.winning_option(proposal_id), Some(1));
+    assert!(contract.has_voted(proposal_id, accounts.alice));
+    assert!(!contract.has_voted(proposal_id, accounts.charlie));
+
+    // The voting period is over
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Succeeded
+    );
+}
+
+#[ink::test]
+/// A tie between the options defeats the proposal
+fn tied_options_are_defeated() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 0).unwrap();
+    set_caller(accounts.charlie);
+    contract.cast_vote(proposal_id, 2).unwrap();
+
+    assert_eq!(contract.winning_option(proposal_id), None);
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
+
+#[ink::test]
+/// The support must be the index of an option
+fn vote_on_missing_option_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let err_response = contract.cast_vote(proposal_id, 3).unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::InvalidVoteType"))
+    );
+}
+
+#[ink::test]
+/// The votes of an option cannot overflow
+fn votes_overflow_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: u64::MAX,
+    };
+    contract.update_members(vec![bob_member], vec![]).unwrap();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 0).unwrap();
+    set_caller(accounts.bob);
+    let err_response = contract.cast_vote(proposal_id, 0).unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VotesOverflow"))
+    );
+    assert_eq!(contract.option_votes(proposal_id), vec![2, 0, 0]);
+}
+
+#[ink::test]
+/// A proposal without votes is defeated once the voting period is over
+fn proposal_without_votes_is_defeated() {
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(contract.winning_option(proposal_id), None);
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
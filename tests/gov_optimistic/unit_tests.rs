@@ -0,0 +1,210 @@
+use ink::env::{
+    test::DefaultAccounts,
+    DefaultEnvironment,
+};
+
+use crate::gov_optimistic::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_counting_optimistic::*,
+    governor_voting_group::*,
+    traits::governance::modules::counter::Counter,
+};
+
+const VETO_THRESHOLD: u64 = 2;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 3,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 2,
+    };
+    let charlie_member = VotingMember {
+        account: accounts.charlie,
+        voting_power: 1,
+    };
+
+    let init_members = vec![alice_member, bob_member, charlie_member];
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, init_members, VETO_THRESHOLD, vec![accounts.bob]).unwrap()
+}
+
+fn propose(contract: &mut Contract) -> ProposalId {
+    let accounts = default_accounts();
+
+    set_caller(accounts.bob);
+    let proposal = Proposal::default();
+    let description = String::from("Test proposal");
+    contract.propose(proposal, description).unwrap()
+}
+
+fn end_voting_period() {
+    for _ in 0..3 {
+        ink::env::test::advance_block::<DefaultEnvironment>();
+    }
+}
+
+#[ink::test]
+fn contruction_works() {
+    let accounts = default_accounts();
+    let contract = build_contract();
+
+    assert_eq!(contract.veto_threshold(), VETO_THRESHOLD);
+    assert!(contract.is_optimistic_proposer(accounts.bob));
+    assert!(!contract.is_optimistic_proposer(accounts.alice));
+    assert_eq!(
+        contract.counting_mode(),
+        String::from("support=bravo&quorum=none&success=against<=threshold")
+    );
+}
+
+#[ink::test]
+fn set_veto_threshold_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    contract.set_veto_threshold(4).unwrap();
+    assert_eq!(contract.veto_threshold(), 4);
+
+    set_caller(accounts.bob);
+    let err_response = contract.set_veto_threshold(0).unwrap_err();
+    assert_eq!(err_response, GovernorError::OnlyGovernance);
+}
+
+#[ink::test]
+/// A proposal without votes succeeds at the deadline
+fn proposal_without_votes_succeeds() {
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    end_voting_period();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Succeeded
+    );
+}
+
+#[ink::test]
+/// The quorum is reached even if the voting module does not provide it
+fn quorum_without_source_is_reached() {
+    let counting = governor_counting_optimistic::Counting::default();
+
+    assert_eq!(
+        Counter::_quorum_reached(&counting, &ProposalId::default(), None),
+        Ok(true)
+    );
+}
+
+#[ink::test]
+/// A proposal succeeds while its against votes do not exceed the veto threshold
+fn against_votes_below_threshold_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 1).unwrap();
+    set_caller(accounts.charlie);
+    contract.cast_vote(proposal_id, 3).unwrap();
+
+    let proposal_votes = contract.proposal_votes(proposal_id).unwrap();
+    assert_eq!(proposal_votes.against_votes, 2);
+    assert_eq!(proposal_votes.abstain_votes, 1);
+
+    end_voting_period();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Succeeded
+    );
+}
+
+#[ink::test]
+/// A proposal whose against votes exceed the veto threshold is defeated
+fn vetoed_proposal_is_defeated() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 1).unwrap();
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 2).unwrap();
+
+    end_voting_period();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
+
+#[ink::test]
+fn invalid_vote_type_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let err_response = contract.cast_vote(proposal_id, 4).unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::InvalidVoteType"))
+    );
+}
+
+#[ink::test]
+fn only_optimistic_proposers_can_propose() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    set_caller(accounts.charlie);
+    let err_response = contract
+        .propose(Proposal::default(), String::from("First proposal"))
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::NotOptimisticProposer);
+
+    let err_response = contract
+        .set_optimistic_proposer(accounts.charlie, true)
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::OnlyGovernance);
+
+    set_caller(accounts.alice);
+    contract
+        .set_optimistic_proposer(accounts.charlie, true)
+        .unwrap();
+    contract
+        .set_optimistic_proposer(accounts.bob, false)
+        .unwrap();
+    assert!(contract.is_optimistic_proposer(accounts.charlie));
+    assert!(!contract.is_optimistic_proposer(accounts.bob));
+
+    set_caller(accounts.charlie);
+    assert!(contract
+        .propose(Proposal::default(), String::from("Second proposal"))
+        .is_ok());
+}
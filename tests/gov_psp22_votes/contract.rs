@@ -6,6 +6,7 @@ pub mod gov_psp22_votes {
     use ink_governance::{
         governor::*,
         governor_counting_simple::*,
+        governor_votes_quorum_fraction::*,
     };
 
     use ink_governance::psp22_votes::*;
@@ -29,6 +30,8 @@ pub mod gov_psp22_votes {
         psp22_votes: psp22_votes::Data,
         #[storage_field]
         psp22: psp22::Data,
+        #[storage_field]
+        quorum_fraction: governor_votes_quorum_fraction::Data,
     }
 
     impl Governor for Contract {}
@@ -37,6 +40,7 @@ pub mod gov_psp22_votes {
     impl PSP22 for Contract {}
     impl PSP22Votes for Contract {}
     impl PSP22Burnable for Contract {}
+    impl GovernorVotesQuorumFraction for Contract {}
 
     impl Transfer for Contract {
         fn _after_token_transfer(
@@ -74,6 +78,19 @@ pub mod gov_psp22_votes {
             }
             Ok(votes)
         }
+        fn _quorum(&self, block_number: BlockNumber) -> Result<u64, GovernorError> {
+            self._quorum_fraction(block_number)
+        }
+    }
+
+    impl governor_votes_quorum_fraction::Internal for Contract {
+        fn _get_past_total_supply(
+            &self,
+            block_number: BlockNumber,
+        ) -> Result<u64, GovernorError> {
+            self.get_past_total_supply(block_number)
+                .map_err(|_| GovernorError::NoVotes)
+        }
     }
 
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -93,6 +110,11 @@ pub mod gov_psp22_votes {
                 ._mint_to(Self::env().caller(), total_supply)
                 .expect("Should mint");
 
+            // The quorum is the 4% of the total supply at the proposal snapshot.
+            instance
+                ._init_with_quorum_numerator(4)
+                .expect("Should set quorum numerator");
+
             instance
         }
     }
@@ -198,10 +198,12 @@ async fn e2e_can_complete_total_process(
     let selector = <[u8; 4]>::from_hex(selector_hex).expect("Decoding failed");
 
     let proposal = Proposal {
-        callee: contract_acc_id,
-        selector,
-        input,
-        transferred_value: 0,
+        transactions: vec![Transaction {
+            callee: contract_acc_id,
+            selector,
+            input,
+            transferred_value: 0,
+        }],
     };
     let description = String::from("Test proposal");
     let description_hash = Hash::try_from(blake2x256!("Test proposal")).unwrap();
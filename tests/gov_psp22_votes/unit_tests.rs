@@ -29,6 +29,7 @@ use openbrush::{
 use ink_governance::{
     governor::*,
     governor_counting_simple::*,
+    governor_votes_quorum_fraction::*,
 };
 
 fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
@@ -144,3 +145,45 @@ fn cast_vote_works() {
     let response = contract.proposal_votes(proposal_id).unwrap();
     assert_eq!(response, proposal_votes);
 }
+
+#[ink::test]
+/// The quorum is a fraction of the total supply at the proposal snapshot
+fn quorum_fraction_works() {
+    let accounts = default_accounts();
+
+    let mut contract = build_contract(accounts.alice, 1000);
+
+    assert_eq!(contract.quorum_numerator(), 4);
+    assert_eq!(contract.quorum_denominator(), 100);
+    assert_eq!(contract.quorum_at(0).unwrap(), 40);
+    // The governor takes the quorum from the extension
+    assert_eq!(contract.quorum(0).unwrap(), 40);
+
+    let err = contract.update_quorum_numerator(101).unwrap_err();
+    assert_eq!(err, GovernorError::InvalidQuorumFraction);
+
+    contract.transfer(accounts.bob, 10, Vec::new()).unwrap();
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    let proposal_id = propose(&mut contract);
+
+    // Bob votes are below the quorum
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 2).unwrap();
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    // In this case since we are in an off-chain envoriment, the modifier only_governance is not applied
+    // and so we can update the quorum numerator without a passed proposal.
+    set_caller(accounts.alice);
+    contract.update_quorum_numerator(1).unwrap();
+    assert_eq!(contract.quorum_numerator(), 1);
+    assert_eq!(contract.quorum_numerator_at(1), 4);
+    assert_eq!(contract.quorum_at(2).unwrap(), 10);
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+
+    // The new numerator does not apply retroactively to the proposal
+    let response = contract.state(proposal_id).unwrap();
+    assert_eq!(response, ProposalState::Defeated);
+}
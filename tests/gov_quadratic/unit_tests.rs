@@ -0,0 +1,322 @@
+use ink::env::{
+    test::DefaultAccounts,
+    DefaultEnvironment,
+};
+
+use crate::gov_quadratic::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        Storage,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_counting_quadratic::*,
+    governor_voting_group::*,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 16,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 9,
+    };
+    let charlie_member = VotingMember {
+        account: accounts.charlie,
+        voting_power: 2,
+    };
+
+    let init_members = vec![alice_member, bob_member, charlie_member];
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, init_members).unwrap()
+}
+
+fn propose(contract: &mut Contract) -> ProposalId {
+    let accounts = default_accounts();
+
+    set_caller(accounts.bob);
+    let proposal = Proposal::default();
+    let description = String::from("Test proposal");
+    contract.propose(proposal, description).unwrap()
+}
+
+fn quadratic_params(against_votes: u64, for_votes: u64, abstain_votes: u64) -> Vec<u8> {
+    scale::Encode::encode(&ProposalVote {
+        against_votes,
+        for_votes,
+        abstain_votes,
+    })
+}
+
+#[ink::test]
+/// A vote without params assigns the square root of the weight to the support
+fn nominal_vote_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let response = contract.cast_vote(proposal_id, 2).unwrap();
+    assert_eq!(response, 16);
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 1).unwrap();
+    set_caller(accounts.charlie);
+    contract.cast_vote(proposal_id, 2).unwrap();
+
+    let proposal_votes = ProposalVote {
+        against_votes: 3,
+        for_votes: 5,
+        abstain_votes: 0,
+    };
+    assert_eq!(
+        contract.proposal_votes(proposal_id).unwrap(),
+        proposal_votes
+    );
+    assert_eq!(contract.used_credits(proposal_id, accounts.alice), 16);
+    // The credits that cannot buy a whole vote are not spent
+    assert_eq!(contract.used_credits(proposal_id, accounts.charlie), 1);
+    assert!(contract.has_voted(proposal_id, accounts.bob));
+}
+
+#[ink::test]
+/// A vote with params spends the credits between the vote types
+fn quadratic_vote_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            quadratic_params(1, 3, 0),
+        )
+        .unwrap();
+    assert_eq!(contract.used_credits(proposal_id, accounts.alice), 10);
+
+    // The account can vote again until its credits are spent
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            quadratic_params(0, 0, 2),
+        )
+        .unwrap();
+    assert_eq!(contract.used_credits(proposal_id, accounts.alice), 14);
+
+    let proposal_votes = ProposalVote {
+        against_votes: 1,
+        for_votes: 3,
+        abstain_votes: 2,
+    };
+    assert_eq!(
+        contract.voter_votes(proposal_id, accounts.alice),
+        proposal_votes
+    );
+    assert_eq!(
+        contract.proposal_votes(proposal_id).unwrap(),
+        proposal_votes
+    );
+}
+
+#[ink::test]
+/// Splitting the votes in several calls does not make them cheaper
+fn split_votes_exceeding_credits_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    for _ in 0..2 {
+        contract
+            .cast_vote_with_reason_and_params(
+                proposal_id,
+                0,
+                String::from(""),
+                quadratic_params(0, 2, 0),
+            )
+            .unwrap();
+    }
+    assert_eq!(contract.used_credits(proposal_id, accounts.alice), 16);
+
+    let err_response = contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            quadratic_params(0, 1, 0),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VoteWeightExceeded"))
+    );
+}
+
+#[ink::test]
+/// The cost of the votes is computed without overflowing
+fn overflowing_votes_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            quadratic_params(0, u64::MAX, 0),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VoteWeightExceeded"))
+    );
+}
+
+#[ink::test]
+/// A nominal vote is not allowed after a quadratic vote
+fn nominal_vote_after_quadratic_vote_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            quadratic_params(1, 0, 0),
+        )
+        .unwrap();
+
+    let err_response = contract.cast_vote(proposal_id, 2).unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VoteAlreadyCast"))
+    );
+}
+
+#[ink::test]
+/// The quorum counts the credits spent on for and abstain votes
+fn turnout_counts_credits_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            quadratic_params(1, 3, 0),
+        )
+        .unwrap();
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            quadratic_params(0, 0, 2),
+        )
+        .unwrap();
+
+    let turnout = Storage::<
+        governor::Data<
+            governor_counting_quadratic::Counting,
+            governor_voting_group::Voting,
+        >,
+    >::data(&mut contract)
+    .counting_module
+    .proposal_turnout
+    .get(&proposal_id);
+    assert_eq!(turnout, Some(13));
+}
+
+#[ink::test]
+/// Invalid params are rejected
+fn invalid_params_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(proposal_id, 0, String::from(""), vec![1, 2, 3])
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::InvalidParams"))
+    );
+}
+
+#[ink::test]
+/// A proposal without votes is defeated once the voting period is over
+fn proposal_without_votes_is_defeated() {
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
+
+#[ink::test]
+/// A proposal with as many for votes as against votes is defeated
+fn tied_proposal_is_defeated() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract);
+
+    set_caller(accounts.alice);
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            quadratic_params(0, 3, 0),
+        )
+        .unwrap();
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 0).unwrap();
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
@@ -0,0 +1,355 @@
+use ink::env::{
+    test::DefaultAccounts,
+    DefaultEnvironment,
+};
+
+use crate::gov_ranked_choice::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_counting_ranked_choice::*,
+    governor_voting_group::*,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 2,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 1,
+    };
+    let charlie_member = VotingMember {
+        account: accounts.charlie,
+        voting_power: 1,
+    };
+    let django_member = VotingMember {
+        account: accounts.django,
+        voting_power: 1,
+    };
+
+    let init_members = vec![alice_member, bob_member, charlie_member, django_member];
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, init_members).unwrap()
+}
+
+fn options() -> Vec<String> {
+    vec![
+        String::from("Option A"),
+        String::from("Option B"),
+        String::from("Option C"),
+    ]
+}
+
+fn propose(contract: &mut Contract, mode: RankingMode) -> ProposalId {
+    let accounts = default_accounts();
+
+    set_caller(accounts.bob);
+    let proposal = Proposal::default();
+    let description = String::from("Test proposal");
+    contract
+        .propose_with_options(proposal, description, options(), mode)
+        .unwrap()
+}
+
+fn rank(contract: &mut Contract, proposal_id: ProposalId, preferences: Vec<u8>) {
+    contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            scale::Encode::encode(&preferences),
+        )
+        .unwrap();
+}
+
+/// Alice ranks A then B, Bob B then C, Charlie C then B and Django B then A
+fn cast_ballots(contract: &mut Contract, proposal_id: ProposalId) {
+    let accounts = default_accounts();
+
+    set_caller(accounts.alice);
+    rank(contract, proposal_id, vec![0, 1]);
+    set_caller(accounts.bob);
+    rank(contract, proposal_id, vec![1, 2]);
+    set_caller(accounts.charlie);
+    rank(contract, proposal_id, vec![2, 1]);
+    set_caller(accounts.django);
+    rank(contract, proposal_id, vec![1, 0]);
+}
+
+#[ink::test]
+/// Propose with options works correctly
+fn propose_with_options_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    assert_eq!(
+        contract.counting_mode(),
+        String::from(
+            "support=ranked&quorum=participation&params=preferences&options=proposal_options"
+        )
+    );
+
+    set_caller(accounts.bob);
+    let err_response = contract
+        .propose_with_options(
+            Proposal::default(),
+            String::from("Test proposal"),
+            vec![String::from("Option A")],
+            RankingMode::InstantRunoff,
+        )
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::InvalidProposalOptions);
+
+    let proposal_id = propose(&mut contract, RankingMode::Borda);
+    assert_eq!(contract.proposal_options(proposal_id), options());
+    assert_eq!(contract.ranking_mode(proposal_id), Some(RankingMode::Borda));
+    assert_eq!(
+        contract.winning_options(proposal_id).unwrap(),
+        Vec::<u8>::new()
+    );
+}
+
+#[ink::test]
+/// The option with the fewest votes is eliminated until an option has the majority
+fn instant_runoff_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract, RankingMode::InstantRunoff);
+
+    cast_ballots(&mut contract, proposal_id);
+    assert!(contract.has_voted(proposal_id, accounts.charlie));
+
+    // Option C is eliminated and the ballot of Charlie is transferred to option B
+    assert_eq!(
+        contract.rounds(proposal_id).unwrap(),
+        vec![
+            Round {
+                tallies: vec![2, 2, 1],
+                eliminated: vec![2],
+            },
+            Round {
+                tallies: vec![2, 3, 0],
+                eliminated: vec![],
+            },
+        ]
+    );
+    assert_eq!(contract.winning_options(proposal_id).unwrap(), vec![1]);
+
+    // The voting period is over, the proposal is not successful until it is tallied
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+
+    contract.tally(proposal_id).unwrap();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Succeeded
+    );
+}
+
+#[ink::test]
+/// The winning options are stored once the voting period is over
+fn tally_works() {
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract, RankingMode::InstantRunoff);
+
+    cast_ballots(&mut contract, proposal_id);
+    let err_response = contract.tally(proposal_id).unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotFinished);
+
+    // The voting period is over
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(contract.tally(proposal_id), Ok(vec![1]));
+    assert_eq!(contract.winning_options(proposal_id).unwrap(), vec![1]);
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Succeeded
+    );
+}
+
+#[ink::test]
+/// The option with the most points wins with the Borda count
+fn borda_works() {
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract, RankingMode::Borda);
+
+    cast_ballots(&mut contract, proposal_id);
+
+    assert_eq!(
+        contract.rounds(proposal_id).unwrap(),
+        vec![Round {
+            tallies: vec![5, 7, 3],
+            eliminated: vec![],
+        }]
+    );
+    assert_eq!(contract.winning_options(proposal_id).unwrap(), vec![1]);
+
+    // The Borda points are counted with every vote, so the proposal does not need a tally
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Succeeded
+    );
+}
+
+#[ink::test]
+/// A tie between the remaining options defeats the proposal
+fn tied_options_are_defeated() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract, RankingMode::InstantRunoff);
+
+    // A vote without params ranks only the support
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 0).unwrap();
+    set_caller(accounts.charlie);
+    contract.cast_vote(proposal_id, 2).unwrap();
+
+    assert_eq!(contract.winning_options(proposal_id).unwrap(), vec![0, 2]);
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(contract.tally(proposal_id), Ok(vec![0, 2]));
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
+
+#[ink::test]
+/// An option can be ranked only once
+fn duplicated_preference_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract, RankingMode::InstantRunoff);
+
+    set_caller(accounts.alice);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            scale::Encode::encode(&vec![0u8, 1, 0]),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::InvalidVoteType"))
+    );
+}
+
+#[ink::test]
+/// The preferences must be indexes of the options
+fn missing_option_preference_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract, RankingMode::Borda);
+
+    set_caller(accounts.alice);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            scale::Encode::encode(&vec![1u8, 3]),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::InvalidVoteType"))
+    );
+}
+
+#[ink::test]
+/// A ballot must rank at least one option
+fn empty_preferences_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract, RankingMode::InstantRunoff);
+
+    set_caller(accounts.alice);
+    let err_response = contract
+        .cast_vote_with_reason_and_params(
+            proposal_id,
+            0,
+            String::from(""),
+            scale::Encode::encode(&Vec::<u8>::new()),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::InvalidParams"))
+    );
+}
+
+#[ink::test]
+/// The votes of a proposal cannot overflow
+fn votes_overflow_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: u64::MAX,
+    };
+    contract.update_members(vec![bob_member], vec![]).unwrap();
+    let proposal_id = propose(&mut contract, RankingMode::Borda);
+
+    set_caller(accounts.alice);
+    rank(&mut contract, proposal_id, vec![0]);
+    set_caller(accounts.bob);
+    let err_response = contract.cast_vote(proposal_id, 1).unwrap_err();
+    assert_eq!(
+        err_response,
+        GovernorError::Custom(String::from("C::VotesOverflow"))
+    );
+    assert!(!contract.has_voted(proposal_id, accounts.bob));
+}
+
+#[ink::test]
+/// A proposal without votes is defeated once the voting period is over
+fn proposal_without_votes_is_defeated() {
+    let mut contract = build_contract();
+    let proposal_id = propose(&mut contract, RankingMode::InstantRunoff);
+
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    assert_eq!(contract.tally(proposal_id), Ok(vec![]));
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
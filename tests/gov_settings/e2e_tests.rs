@@ -51,10 +51,12 @@ async fn e2e_can_update_settings(mut client: ink_e2e::Client<C, E>) -> E2EResult
     let selector = <[u8; 4]>::from_hex(selector_hex).expect("Decoding failed");
 
     let proposal = Proposal {
-        callee: contract_acc_id,
-        selector,
-        input,
-        transferred_value: 0,
+        transactions: vec![Transaction {
+            callee: contract_acc_id,
+            selector,
+            input,
+            transferred_value: 0,
+        }],
     };
     let description = String::from("Set a new voting period");
     let description_hash =
@@ -135,3 +137,257 @@ async fn e2e_can_update_settings(mut client: ink_e2e::Client<C, E>) -> E2EResult
 
     Ok(())
 }
+
+// Test to cover that a call that returns `Err` reverts the execution, when it is performed by
+// `execute` or relayed by `relay`.
+#[ink_e2e::test]
+async fn e2e_failing_call_reverts_execution(
+    mut client: ink_e2e::Client<C, E>,
+) -> E2EResult<()> {
+    let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+    let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+    let alice_member = VotingMember {
+        account: alice,
+        voting_power: 1,
+    };
+    let bob_member = VotingMember {
+        account: bob,
+        voting_power: 1,
+    };
+    let init_members = vec![alice_member, bob_member];
+    let constructor = ContractRef::new(None, init_members, 0, 2, 0);
+    let contract_acc_id = client
+        .instantiate("gov_settings", &ink_e2e::alice(), constructor, 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    // `update_members` without members returns `Err(ZeroMembers)`
+    let mut input = scale::Encode::encode(&Vec::<VotingMember>::new());
+    input.append(&mut scale::Encode::encode(&Vec::<
+        openbrush::traits::AccountId,
+    >::new()));
+    let update_members = Transaction {
+        callee: contract_acc_id,
+        selector: ink::selector_bytes!("VotingGroup::update_members"),
+        input,
+        transferred_value: 0,
+    };
+
+    // The first proposal calls `update_members`, the second one relays the same call
+    let executed_proposal = Proposal {
+        transactions: vec![update_members.clone()],
+    };
+    let executed_description = String::from("Executed call");
+    let executed_description_hash = Hash::try_from(blake2x256!("Executed call")).unwrap();
+    let relayed_proposal = Proposal {
+        transactions: vec![Transaction {
+            callee: contract_acc_id,
+            selector: ink::selector_bytes!("Governor::relay"),
+            input: scale::Encode::encode(&update_members),
+            transferred_value: 0,
+        }],
+    };
+    let relayed_description = String::from("Relayed call");
+    let relayed_description_hash = Hash::try_from(blake2x256!("Relayed call")).unwrap();
+
+    let mut proposal_ids = Vec::new();
+    for (proposal, description) in [
+        (executed_proposal.clone(), executed_description),
+        (relayed_proposal.clone(), relayed_description),
+    ] {
+        let propose = build_message::<ContractRef>(contract_acc_id.clone())
+            .call(|gov| gov.propose(proposal.clone(), description.clone().into()));
+        let proposal_id = client
+            .call_dry_run(&ink_e2e::bob(), &propose, 0, None)
+            .await
+            .return_value()
+            .unwrap();
+        client
+            .call(&ink_e2e::bob(), propose, 0, None)
+            .await
+            .unwrap();
+
+        let for_vote = build_message::<ContractRef>(contract_acc_id.clone())
+            .call(|gov| gov.cast_vote(proposal_id, 2));
+        client
+            .call(&ink_e2e::alice(), for_vote, 0, None)
+            .await
+            .unwrap();
+        proposal_ids.push(proposal_id);
+    }
+
+    // Do two extrinsincs to advance the block (instant_finality)
+    // TODO: delete if ink_e2e update
+    for _ in 0..2 {
+        let proposal_state = build_message::<ContractRef>(contract_acc_id.clone())
+            .call(|gov| gov.state(proposal_ids[1]));
+        client
+            .call(&ink_e2e::bob(), proposal_state, 0, None)
+            .await
+            .unwrap();
+    }
+
+    // The `Err` returned by `update_members` reverts the execution with the encoded error
+    let execute = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.execute(executed_proposal.clone(), executed_description_hash));
+    let execute_res = client
+        .call_dry_run(&ink_e2e::alice(), &execute, 0, None)
+        .await
+        .return_value();
+    assert_eq!(
+        execute_res,
+        Err(GovernorError::CallReverted(scale::Encode::encode(
+            &VotingGroupError::ZeroMembers
+        )))
+    );
+
+    // The `Err` is returned by `relay`, that reverts the execution in turn
+    let execute = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.execute(relayed_proposal.clone(), relayed_description_hash));
+    let execute_res = client
+        .call_dry_run(&ink_e2e::alice(), &execute, 0, None)
+        .await
+        .return_value();
+    assert_eq!(
+        execute_res,
+        Err(GovernorError::CallReverted(scale::Encode::encode(
+            &GovernorError::CallReverted(scale::Encode::encode(
+                &VotingGroupError::ZeroMembers
+            ))
+        )))
+    );
+
+    // The proposals are not executed
+    for proposal_id in proposal_ids {
+        let proposal_state = build_message::<ContractRef>(contract_acc_id.clone())
+            .call(|gov| gov.state(proposal_id));
+        let proposal_state_res = client
+            .call_dry_run(&ink_e2e::bob(), &proposal_state, 0, None)
+            .await
+            .return_value()
+            .unwrap();
+        assert_eq!(proposal_state_res, ProposalState::Succeeded);
+    }
+
+    Ok(())
+}
+
+// Test to cover that the transactions of a proposal are executed atomically: a failing
+// transaction reverts the transactions executed before it.
+#[ink_e2e::test]
+async fn e2e_failing_batch_is_reverted(
+    mut client: ink_e2e::Client<C, E>,
+) -> E2EResult<()> {
+    let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+    let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+
+    let alice_member = VotingMember {
+        account: alice,
+        voting_power: 1,
+    };
+    let bob_member = VotingMember {
+        account: bob,
+        voting_power: 1,
+    };
+    let init_members = vec![alice_member, bob_member];
+    let constructor = ContractRef::new(None, init_members, 0, 2, 0);
+    let contract_acc_id = client
+        .instantiate("gov_settings", &ink_e2e::alice(), constructor, 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    // The proposal sets a new voting period, then calls `update_members` without members
+    let mut input = scale::Encode::encode(&Vec::<VotingMember>::new());
+    input.append(&mut scale::Encode::encode(&Vec::<
+        openbrush::traits::AccountId,
+    >::new()));
+    let proposal = Proposal {
+        transactions: vec![
+            Transaction {
+                callee: contract_acc_id,
+                selector: ink::selector_bytes!("GovernorSettings::set_voting_period"),
+                input: scale::Encode::encode(&3u32),
+                transferred_value: 0,
+            },
+            Transaction {
+                callee: contract_acc_id,
+                selector: ink::selector_bytes!("VotingGroup::update_members"),
+                input,
+                transferred_value: 0,
+            },
+        ],
+    };
+    let description = String::from("Failing batch");
+    let description_hash = Hash::try_from(blake2x256!("Failing batch")).unwrap();
+
+    let propose = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.propose(proposal.clone(), description.clone().into()));
+    let proposal_id = client
+        .call_dry_run(&ink_e2e::bob(), &propose, 0, None)
+        .await
+        .return_value()
+        .unwrap();
+    client
+        .call(&ink_e2e::bob(), propose, 0, None)
+        .await
+        .unwrap();
+
+    let for_vote = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.cast_vote(proposal_id, 2));
+    client
+        .call(&ink_e2e::alice(), for_vote, 0, None)
+        .await
+        .unwrap();
+
+    // Do two extrinsincs to advance the block (instant_finality)
+    // TODO: delete if ink_e2e update
+    for _ in 0..2 {
+        let proposal_state = build_message::<ContractRef>(contract_acc_id.clone())
+            .call(|gov| gov.state(proposal_id));
+        client
+            .call(&ink_e2e::bob(), proposal_state, 0, None)
+            .await
+            .unwrap();
+    }
+
+    // The execution fails with the error of the second transaction
+    let execute = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.execute(proposal.clone(), description_hash));
+    let execute_res = client
+        .call_dry_run(&ink_e2e::alice(), &execute, 0, None)
+        .await
+        .return_value();
+    assert_eq!(
+        execute_res,
+        Err(GovernorError::CallReverted(scale::Encode::encode(
+            &VotingGroupError::ZeroMembers
+        )))
+    );
+    assert!(client
+        .call(&ink_e2e::alice(), execute, 0, None)
+        .await
+        .is_err());
+
+    // The voting period set by the first transaction is reverted
+    let voting_period = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.voting_period());
+    let voting_period_res = client
+        .call_dry_run(&ink_e2e::alice(), &voting_period, 0, None)
+        .await
+        .return_value();
+    assert_eq!(voting_period_res, 2);
+
+    let proposal_state = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.state(proposal_id));
+    let proposal_state_res = client
+        .call_dry_run(&ink_e2e::bob(), &proposal_state, 0, None)
+        .await
+        .return_value()
+        .unwrap();
+    assert_eq!(proposal_state_res, ProposalState::Succeeded);
+
+    Ok(())
+}
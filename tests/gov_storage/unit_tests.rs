@@ -0,0 +1,147 @@
+use ink::{
+    codegen::Env,
+    env::{
+        hash::Blake2x256,
+        test::DefaultAccounts,
+        DefaultEnvironment,
+    },
+};
+
+use crate::gov_storage::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        Hash,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_storage::*,
+    governor_voting_group::*,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 1,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 1,
+    };
+
+    let init_members = vec![alice_member, bob_member];
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, init_members).unwrap()
+}
+
+fn description_hash(contract: &Contract, description: &String) -> Hash {
+    Hash::try_from(
+        contract
+            .env()
+            .hash_bytes::<Blake2x256>(description)
+            .as_ref(),
+    )
+    .unwrap()
+}
+
+#[ink::test]
+/// Proposals are stored in order of creation
+fn proposals_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    assert_eq!(contract.proposal_count(), 0);
+    assert!(contract.proposals(0, 10).is_empty());
+
+    set_caller(accounts.bob);
+    let descriptions = [
+        String::from("First proposal"),
+        String::from("Second proposal"),
+        String::from("Third proposal"),
+    ];
+    let proposal_ids: Vec<ProposalId> = descriptions
+        .iter()
+        .map(|description| {
+            contract
+                .propose(Proposal::default(), description.clone())
+                .unwrap()
+        })
+        .collect();
+
+    assert_eq!(contract.proposal_count(), 3);
+
+    let details = ProposalDetails {
+        proposer: accounts.bob,
+        proposal: Proposal::default(),
+        description_hash: description_hash(&contract, &descriptions[1]),
+    };
+    assert_eq!(
+        contract.proposal_details(proposal_ids[1]),
+        Some(details.clone())
+    );
+    assert_eq!(contract.proposal_details(Hash::default()), None);
+
+    let response = contract.proposals(1, 10);
+    assert_eq!(response.len(), 2);
+    assert_eq!(response[0], (proposal_ids[1], details));
+    assert_eq!(response[1].0, proposal_ids[2]);
+
+    let response = contract.proposals(0, 1);
+    assert_eq!(response.len(), 1);
+    assert_eq!(response[0].0, proposal_ids[0]);
+
+    assert!(contract.proposals(3, 10).is_empty());
+}
+
+#[ink::test]
+/// Queue, execute and cancel by id works correctly
+fn by_id_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    let err_response = contract.cancel_by_id(Hash::default()).unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotFound);
+
+    set_caller(accounts.bob);
+    let proposal_id = contract
+        .propose(Proposal::default(), String::from("Test proposal"))
+        .unwrap();
+
+    // There is no queueing mechanism configured.
+    let err_response = contract.queue_by_id(proposal_id).unwrap_err();
+    assert_eq!(err_response, GovernorError::QueueNotSupported);
+
+    let err_response = contract.execute_by_id(proposal_id).unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotSuccessful);
+
+    // In this case since we are in an off-chain envoriment, the modifier only_governance is not applied
+    // and so we can set the guardian without a passed proposal.
+    set_caller(accounts.alice);
+    contract.set_guardian(Some(accounts.alice)).unwrap();
+
+    let response = contract.cancel_by_id(proposal_id).unwrap();
+    assert_eq!(response, proposal_id);
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Canceled
+    );
+}
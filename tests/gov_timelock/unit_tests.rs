@@ -0,0 +1,146 @@
+use ink::{
+    codegen::Env,
+    env::{
+        hash::Blake2x256,
+        test::{
+            DefaultAccounts,
+            EmittedEvent,
+        },
+        DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+};
+
+use crate::gov_timelock::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        Hash,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_timelock_control::*,
+    governor_voting_group::*,
+};
+
+type Event = <Contract as ::ink::reflect::ContractEventBase>::Type;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 1,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 1,
+    };
+
+    let init_members = vec![alice_member, bob_member];
+
+    set_caller(accounts.alice);
+
+    // In this case the timelock is a simple account since in an off-chain envoriment we can't
+    // deploy the timelock contract.
+    Contract::new(None, init_members, accounts.django, None).unwrap()
+}
+
+fn decode_events(emittend_events: Vec<EmittedEvent>) -> Vec<Event> {
+    emittend_events
+        .into_iter()
+        .map(|event| {
+            <Event as scale::Decode>::decode(&mut &event.data[..]).expect("invalid data")
+        })
+        .collect()
+}
+
+#[ink::test]
+/// The constructor does its job
+fn contruction_works() {
+    let accounts = default_accounts();
+    let contract = build_contract();
+
+    let response = contract.timelock();
+    assert_eq!(response, accounts.django);
+
+    let emittend_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let decoded_events = decode_events(emittend_events);
+    if let Event::TimelockChange(TimelockChange {
+        old_timelock,
+        new_timelock,
+    }) = &decoded_events[0]
+    {
+        assert_eq!(old_timelock, &AccountId::from([0x0; 32]));
+        assert_eq!(new_timelock, &accounts.django);
+    } else {
+        panic!("encountered unexpected event kind: expected a TimelockChange event")
+    }
+}
+
+#[ink::test]
+fn queue_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal = Proposal::default();
+    let description = String::from("Test proposal");
+    let description_hash = Hash::try_from(
+        contract
+            .env()
+            .hash_bytes::<Blake2x256>(&description)
+            .as_ref(),
+    )
+    .unwrap();
+
+    let err_response = contract
+        .queue(proposal.clone(), description_hash.clone())
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotFound);
+
+    set_caller(accounts.bob);
+    let proposal_id = contract.propose(proposal.clone(), description).unwrap();
+
+    // A proposal still active can't be queued.
+    let err_response = contract
+        .queue(proposal.clone(), description_hash)
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotSuccessful);
+
+    let response = contract.proposal_eta(proposal_id);
+    assert_eq!(response, None);
+
+    // In this case since we are in an off-chain envoriment we can't test a successfull
+    // queue of the proposal in the timelock.
+}
+
+#[ink::test]
+fn update_timelock_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    // Only the timelock (the executor of the governor) can update the timelock.
+    set_caller(accounts.bob);
+    let err_response = contract.update_timelock(accounts.eve).unwrap_err();
+    assert_eq!(err_response, GovernorError::OnlyGovernance);
+
+    set_caller(accounts.django);
+    contract.update_timelock(accounts.eve).unwrap();
+
+    let response = contract.timelock();
+    assert_eq!(response, accounts.eve);
+}
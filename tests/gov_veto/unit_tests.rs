@@ -0,0 +1,234 @@
+use ink::env::{
+    test::DefaultAccounts,
+    DefaultEnvironment,
+};
+
+use crate::gov_veto::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        Balance,
+        String,
+    },
+};
+
+use ink_governance::{
+    governor::*,
+    governor_counting_veto::*,
+    governor_proposal_deposit::*,
+    governor_voting_group::*,
+};
+
+const DEPOSIT_AMOUNT: Balance = 100;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn set_value_transferred(value: Balance) {
+    ink::env::test::set_value_transferred::<DefaultEnvironment>(value)
+}
+
+fn balance_of(account: AccountId) -> Balance {
+    ink::env::test::get_account_balance::<DefaultEnvironment>(account).unwrap()
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    let alice_member = VotingMember {
+        account: accounts.alice,
+        voting_power: 3,
+    };
+    let bob_member = VotingMember {
+        account: accounts.bob,
+        voting_power: 2,
+    };
+    let charlie_member = VotingMember {
+        account: accounts.charlie,
+        voting_power: 1,
+    };
+
+    let init_members = vec![alice_member, bob_member, charlie_member];
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, init_members, DEPOSIT_AMOUNT).unwrap()
+}
+
+fn propose_with_deposit(contract: &mut Contract) -> ProposalId {
+    let accounts = default_accounts();
+
+    set_caller(accounts.bob);
+    set_value_transferred(DEPOSIT_AMOUNT);
+    let proposal_id = contract
+        .propose_with_deposit(Proposal::default(), String::from("Test proposal"))
+        .unwrap();
+    set_value_transferred(0);
+    proposal_id
+}
+
+fn end_voting_period() {
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    ink::env::test::advance_block::<DefaultEnvironment>();
+}
+
+#[ink::test]
+fn set_veto_threshold_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    assert_eq!(contract.veto_threshold(), VetoThreshold::default());
+
+    let threshold = VetoThreshold {
+        numerator: 1,
+        denominator: 2,
+    };
+    contract.set_veto_threshold(threshold).unwrap();
+    assert_eq!(contract.veto_threshold(), threshold);
+
+    let invalid_thresholds = [
+        VetoThreshold {
+            numerator: 0,
+            denominator: 0,
+        },
+        VetoThreshold {
+            numerator: 2,
+            denominator: 1,
+        },
+    ];
+    for invalid_threshold in invalid_thresholds {
+        let err_response = contract.set_veto_threshold(invalid_threshold).unwrap_err();
+        assert_eq!(err_response, GovernorError::InvalidVetoThreshold);
+    }
+
+    set_caller(accounts.bob);
+    let err_response = contract.set_veto_threshold(threshold).unwrap_err();
+    assert_eq!(err_response, GovernorError::OnlyGovernance);
+}
+
+#[ink::test]
+/// A proposal whose no with veto votes exceed the threshold is defeated even if the for votes
+/// win
+fn vetoed_proposal_is_defeated() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose_with_deposit(&mut contract);
+
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 2).unwrap();
+    set_caller(accounts.bob);
+    contract.cast_vote(proposal_id, 4).unwrap();
+
+    let proposal_votes = contract.proposal_votes(proposal_id).unwrap();
+    assert_eq!(proposal_votes.for_votes, 3);
+    assert_eq!(proposal_votes.no_with_veto_votes, 2);
+
+    end_voting_period();
+    assert!(contract.vetoed(proposal_id));
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Defeated
+    );
+}
+
+#[ink::test]
+/// Below the threshold a no with veto vote counts as an against vote
+fn no_with_veto_below_threshold_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose_with_deposit(&mut contract);
+
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 2).unwrap();
+    set_caller(accounts.charlie);
+    contract.cast_vote(proposal_id, 4).unwrap();
+
+    end_voting_period();
+    assert!(!contract.vetoed(proposal_id));
+    assert_eq!(
+        contract.state(proposal_id).unwrap(),
+        ProposalState::Succeeded
+    );
+}
+
+#[ink::test]
+fn deposit_is_refunded() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose_with_deposit(&mut contract);
+
+    assert_eq!(
+        contract.proposal_deposit(proposal_id),
+        Some(Deposit {
+            depositor: accounts.bob,
+            amount: DEPOSIT_AMOUNT,
+        })
+    );
+
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 2).unwrap();
+
+    let err_response = contract.release_deposit(proposal_id).unwrap_err();
+    assert_eq!(err_response, GovernorError::ProposalNotFinished);
+
+    end_voting_period();
+    let bob_balance = balance_of(accounts.bob);
+    contract.release_deposit(proposal_id).unwrap();
+    assert_eq!(balance_of(accounts.bob), bob_balance + DEPOSIT_AMOUNT);
+    assert_eq!(contract.proposal_deposit(proposal_id), None);
+
+    let err_response = contract.release_deposit(proposal_id).unwrap_err();
+    assert_eq!(err_response, GovernorError::NoDeposit);
+}
+
+#[ink::test]
+fn deposit_is_slashed_on_veto() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let proposal_id = propose_with_deposit(&mut contract);
+
+    set_caller(accounts.alice);
+    contract.cast_vote(proposal_id, 4).unwrap();
+
+    end_voting_period();
+    let bob_balance = balance_of(accounts.bob);
+    contract.release_deposit(proposal_id).unwrap();
+    assert_eq!(balance_of(accounts.bob), bob_balance);
+    assert_eq!(contract.proposal_deposit(proposal_id), None);
+}
+
+#[ink::test]
+fn propose_with_insufficient_deposit_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    set_caller(accounts.bob);
+    set_value_transferred(DEPOSIT_AMOUNT - 1);
+    let err_response = contract
+        .propose_with_deposit(Proposal::default(), String::from("Test proposal"))
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::InsufficientDeposit);
+}
+
+#[ink::test]
+/// A proposal cannot be created without a deposit through the propose message
+fn propose_without_deposit_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    set_caller(accounts.bob);
+    let err_response = contract
+        .propose(Proposal::default(), String::from("Test proposal"))
+        .unwrap_err();
+    assert_eq!(err_response, GovernorError::InsufficientDeposit);
+}
@@ -0,0 +1,113 @@
+use crate::gov_votes::*;
+use ink_governance::{
+    governor::*,
+    governor_counting_simple::*,
+};
+
+use ink_e2e::build_message;
+
+use ink_governance::{
+    governor::governor_external::Governor,
+    governor_counting_simple::countingsimple_external::CountingSimple,
+    governor_votes::governorvotes_external::GovernorVotes,
+};
+
+use psp22_votes::psp22_votes::ContractRef as TokenRef;
+
+type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[ink_e2e::test(additional_contracts = "../psp22_votes/Cargo.toml")]
+async fn e2e_can_vote_with_external_token(
+    mut client: ink_e2e::Client<C, E>,
+) -> E2EResult<()> {
+    // 1)
+    // Instantiate(Mint) the token with init supply
+    let token_constructor = TokenRef::new(1000);
+    let token_acc_id = client
+        .instantiate("psp22_votes", &ink_e2e::alice(), token_constructor, 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    // 2)
+    // Instantiate the governor with the token as source of votes and a quorum of 4%
+    let constructor = ContractRef::new(token_acc_id, 4);
+    let contract_acc_id = client
+        .instantiate("gov_votes", &ink_e2e::alice(), constructor, 0, None)
+        .await
+        .expect("instantiate failed")
+        .account_id;
+
+    let token =
+        build_message::<ContractRef>(contract_acc_id.clone()).call(|gov| gov.token());
+    let token_res = client
+        .call_dry_run(&ink_e2e::alice(), &token, 0, None)
+        .await
+        .return_value();
+    assert_eq!(token_res, token_acc_id);
+
+    // 3)
+    // Propose with the votes held in the token contract
+    let propose = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.propose(Proposal::default(), String::from("Test proposal")));
+    let proposal_id = client
+        .call_dry_run(&ink_e2e::alice(), &propose, 0, None)
+        .await
+        .return_value()
+        .unwrap();
+    client
+        .call(&ink_e2e::alice(), propose, 0, None)
+        .await
+        .unwrap();
+
+    // Charlie holds no token and so cannot vote
+    let against_vote = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.cast_vote(proposal_id, 1));
+    let against_vote_res = client
+        .call_dry_run(&ink_e2e::charlie(), &against_vote, 0, None)
+        .await
+        .return_value();
+    assert_eq!(against_vote_res, Err(GovernorError::NoVotes));
+
+    // 4)
+    // Cast Vote(For) with the whole supply
+    let for_vote = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.cast_vote(proposal_id, 2));
+    client
+        .call(&ink_e2e::alice(), for_vote, 0, None)
+        .await
+        .unwrap();
+
+    let proposal_votes = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.proposal_votes(proposal_id));
+    let proposal_votes_res = client
+        .call_dry_run(&ink_e2e::alice(), &proposal_votes, 0, None)
+        .await
+        .return_value()
+        .unwrap();
+    assert_eq!(proposal_votes_res.for_votes, 1000);
+
+    // Do extrinsincs to advance the blocks after the deadline (instant_finality)
+    // TODO: delete if ink_e2e update
+    for _ in 0..2 {
+        let proposal_state = build_message::<ContractRef>(contract_acc_id.clone())
+            .call(|gov| gov.state(proposal_id));
+        client
+            .call(&ink_e2e::alice(), proposal_state, 0, None)
+            .await
+            .unwrap();
+    }
+
+    let proposal_state = build_message::<ContractRef>(contract_acc_id.clone())
+        .call(|gov| gov.state(proposal_id));
+    let proposal_state_res = client
+        .call_dry_run(&ink_e2e::alice(), &proposal_state, 0, None)
+        .await
+        .return_value()
+        .unwrap();
+
+    // Assert the proposal is Succeeded (the quorum is the 4% of the total supply)
+    assert_eq!(proposal_state_res, ProposalState::Succeeded);
+
+    Ok(())
+}
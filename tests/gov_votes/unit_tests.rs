@@ -0,0 +1,48 @@
+use ink::env::{
+    test::DefaultAccounts,
+    DefaultEnvironment,
+};
+
+use crate::gov_votes::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::AccountId,
+};
+
+use ink_governance::{
+    governor_votes::*,
+    governor_votes_quorum_fraction::*,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+#[ink::test]
+/// The constructor does its job
+fn contruction_works() {
+    let accounts = default_accounts();
+
+    set_caller(accounts.alice);
+    let contract = Contract::new(accounts.django, 4);
+
+    assert_eq!(contract.token(), accounts.django);
+    assert_eq!(contract.quorum_numerator(), 4);
+}
+
+#[ink::test]
+/// The constructor fails with a quorum numerator greater than the denominator
+#[should_panic(expected = "Should set quorum numerator")]
+fn contruction_fails_with_invalid_quorum() {
+    let accounts = default_accounts();
+
+    set_caller(accounts.alice);
+    Contract::new(accounts.django, 101);
+}
@@ -7,7 +7,10 @@ pub mod psp22_votes {
         EmitEvent,
         Env,
     };
-    use ink_governance::psp22_votes::*;
+    use ink_governance::{
+        psp22_permit::*,
+        psp22_votes::*,
+    };
     use openbrush::{
         contracts::psp22::{
             extensions::burnable::*,
@@ -44,6 +47,28 @@ pub mod psp22_votes {
         pub new_balance: Balance,
     }
 
+    /// Emitted when tokens of an account are locked.
+    #[ink(event)]
+    pub struct TokensLocked {
+        /// Account id of the holder.
+        #[ink(topic)]
+        pub account: AccountId,
+        /// Amount of tokens locked.
+        pub amount: Balance,
+        /// Block number at which the lock expires.
+        pub until: BlockNumber,
+    }
+
+    /// Emitted when the expired locks of an account are removed.
+    #[ink(event)]
+    pub struct TokensUnlocked {
+        /// Account id of the holder.
+        #[ink(topic)]
+        pub account: AccountId,
+        /// Amount of tokens no longer locked.
+        pub unlocked_amount: Balance,
+    }
+
     #[ink(storage)]
     #[derive(Default, Storage)]
     pub struct Contract {
@@ -54,11 +79,24 @@ pub mod psp22_votes {
     }
 
     impl Votes for Contract {}
+    impl DelegateBySig for Contract {}
     impl PSP22 for Contract {}
     impl PSP22Votes for Contract {}
     impl PSP22Burnable for Contract {}
+    impl PSP22Permit for Contract {}
 
     impl Transfer for Contract {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            self._before_token_transfer_locks(from, amount)
+                .map_err(|_| PSP22Error::Custom(String::from("Locked balance")))?;
+            Ok(())
+        }
+
         fn _after_token_transfer(
             &mut self,
             from: Option<&AccountId>,
@@ -96,6 +134,24 @@ pub mod psp22_votes {
                 new_balance,
             })
         }
+        fn _emit_tokens_locked(
+            &self,
+            account: AccountId,
+            amount: Balance,
+            until: BlockNumber,
+        ) {
+            self.env().emit_event(TokensLocked {
+                account,
+                amount,
+                until,
+            })
+        }
+        fn _emit_tokens_unlocked(&self, account: AccountId, unlocked_amount: Balance) {
+            self.env().emit_event(TokensUnlocked {
+                account,
+                unlocked_amount,
+            })
+        }
     }
     impl Contract {
         /// Initialize the contract with a list of voting members and optional admin (if not set
@@ -108,6 +164,9 @@ pub mod psp22_votes {
                 ._mint_to(Self::env().caller(), total_supply)
                 .expect("Should mint");
 
+            // The caller is the locker until it transfers the role (e.g. to a governor).
+            instance._init_locker(Self::env().caller());
+
             instance
         }
     }
@@ -1,12 +1,23 @@
 use crate::psp22_votes::*;
 
-use ink_governance::psp22_votes::*;
+use ink_governance::{
+    psp22_permit::*,
+    psp22_votes::*,
+    traits::errors::{
+        PSP22VotesError,
+        VotesError,
+    },
+};
 use openbrush::{
     contracts::psp22::*,
-    traits::Balance,
+    traits::{
+        Balance,
+        String,
+    },
 };
 
 use ink::env::{
+    hash::Blake2x256,
     test::DefaultAccounts,
     DefaultEnvironment,
 };
@@ -17,6 +28,12 @@ use openbrush::{
     },
     traits::AccountId,
 };
+use secp256k1::{
+    Message,
+    PublicKey,
+    Secp256k1,
+    SecretKey,
+};
 
 fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
     accounts()
@@ -31,6 +48,27 @@ fn build_contract(caller: AccountId, supply: Balance) -> Contract {
     Contract::new(supply)
 }
 
+/// Returns the secret key of an ecdsa key pair and its account
+fn ecdsa_account() -> (SecretKey, AccountId) {
+    let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+    let public_key =
+        PublicKey::from_secret_key(&Secp256k1::new(), &secret_key).serialize();
+    let mut account = [0; 32];
+    ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+    (secret_key, AccountId::from(account))
+}
+
+fn sign(secret_key: &SecretKey, message_hash: [u8; 32]) -> Signature {
+    let message = Message::from_slice(&message_hash).unwrap();
+    let (recovery_id, compact) = Secp256k1::new()
+        .sign_ecdsa_recoverable(&message, secret_key)
+        .serialize_compact();
+    let mut signature = [0; 65];
+    signature[..64].copy_from_slice(&compact);
+    signature[64] = recovery_id.to_i32() as u8;
+    Signature::Ecdsa(signature)
+}
+
 #[ink::test]
 /// The constructor does its job
 fn construction_works() {
@@ -54,3 +92,221 @@ fn construction_works() {
         <u128 as TryInto<u64>>::try_into(total_supply).unwrap()
     );
 }
+
+#[ink::test]
+/// Delegate by signature works correctly
+fn delegate_by_sig_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract(accounts.alice, 1000);
+
+    // The holder is the account of an ecdsa key pair that has never transacted
+    let (secret_key, holder) = ecdsa_account();
+
+    contract.transfer(holder, 100, Vec::new()).unwrap();
+    assert_eq!(contract.delegation_nonces(holder), 0);
+
+    let delegation = Delegation {
+        delegatee: accounts.bob,
+        nonce: 0,
+        expiry: 10,
+    };
+    let signature = sign(&secret_key, contract._hash_delegation(&delegation));
+
+    // Anyone can submit the delegation but only with the signature of the delegator
+    set_caller(accounts.charlie);
+    let err_response = contract
+        .delegate_by_sig(accounts.alice, accounts.bob, 0, 10, signature.clone())
+        .unwrap_err();
+    assert_eq!(err_response, VotesError::InvalidSignature);
+
+    let err_response = contract
+        .delegate_by_sig(holder, accounts.charlie, 0, 10, signature.clone())
+        .unwrap_err();
+    assert_eq!(err_response, VotesError::InvalidSignature);
+
+    let err_response = contract
+        .delegate_by_sig(holder, accounts.bob, 1, 10, signature.clone())
+        .unwrap_err();
+    assert_eq!(err_response, VotesError::InvalidNonce);
+
+    contract
+        .delegate_by_sig(holder, accounts.bob, 0, 10, signature.clone())
+        .unwrap();
+    assert_eq!(contract.delegates(holder), Some(accounts.bob));
+    assert_eq!(contract.get_votes(accounts.bob).unwrap(), 100);
+    assert_eq!(contract.delegation_nonces(holder), 1);
+
+    // The same signature cannot be replayed
+    let err_response = contract
+        .delegate_by_sig(holder, accounts.bob, 0, 10, signature)
+        .unwrap_err();
+    assert_eq!(err_response, VotesError::InvalidNonce);
+
+    // The signature cannot be used after its expiry
+    let delegation = Delegation {
+        delegatee: accounts.charlie,
+        nonce: 1,
+        expiry: 0,
+    };
+    let signature = sign(&secret_key, contract._hash_delegation(&delegation));
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    let err_response = contract
+        .delegate_by_sig(holder, accounts.charlie, 1, 0, signature)
+        .unwrap_err();
+    assert_eq!(err_response, VotesError::SignatureExpired);
+}
+
+#[ink::test]
+/// Permit works correctly
+fn permit_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract(accounts.alice, 1000);
+
+    let (secret_key, owner) = ecdsa_account();
+    contract.transfer(owner, 100, Vec::new()).unwrap();
+
+    let permit = Permit {
+        owner,
+        spender: accounts.bob,
+        value: 50,
+        nonce: 0,
+        deadline: 10,
+    };
+    let signature = sign(&secret_key, contract._hash_permit(&permit));
+
+    // Anyone can submit the permit but only with the signature of the owner
+    set_caller(accounts.charlie);
+    let err_response = contract
+        .permit(owner, accounts.bob, 100, 10, signature.clone())
+        .unwrap_err();
+    assert_eq!(err_response, PSP22PermitError::InvalidSignature);
+
+    contract
+        .permit(owner, accounts.bob, 50, 10, signature.clone())
+        .unwrap();
+    assert_eq!(contract.allowance(owner, accounts.bob), 50);
+
+    // The nonce is shared with the other signed actions of the token
+    assert_eq!(contract.delegation_nonces(owner), 1);
+    let delegation = Delegation {
+        delegatee: accounts.bob,
+        nonce: 0,
+        expiry: 10,
+    };
+    let err_response = contract
+        .delegate_by_sig(
+            owner,
+            accounts.bob,
+            0,
+            10,
+            sign(&secret_key, contract._hash_delegation(&delegation)),
+        )
+        .unwrap_err();
+    assert_eq!(err_response, VotesError::InvalidNonce);
+
+    // The same signature cannot be replayed
+    let err_response = contract
+        .permit(owner, accounts.bob, 50, 10, signature)
+        .unwrap_err();
+    assert_eq!(err_response, PSP22PermitError::InvalidSignature);
+
+    // The signature cannot be used after its deadline
+    let permit = Permit {
+        owner,
+        spender: accounts.bob,
+        value: 100,
+        nonce: 1,
+        deadline: 0,
+    };
+    let signature = sign(&secret_key, contract._hash_permit(&permit));
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    let err_response = contract
+        .permit(owner, accounts.bob, 100, 0, signature)
+        .unwrap_err();
+    assert_eq!(err_response, PSP22PermitError::SignatureExpired);
+
+    // The spender can use the allowance
+    set_caller(accounts.bob);
+    contract
+        .transfer_from(owner, accounts.bob, 50, Vec::new())
+        .unwrap();
+    assert_eq!(contract.balance_of(accounts.bob), 50);
+}
+
+#[ink::test]
+/// Only the locker can lock the tokens and transfer its role
+fn set_locker_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract(accounts.alice, 1000);
+
+    assert_eq!(contract.locker(), Some(accounts.alice));
+
+    set_caller(accounts.bob);
+    let err_response = contract.lock(accounts.alice, 100, 10).unwrap_err();
+    assert_eq!(err_response, PSP22VotesError::NotLocker);
+    let err_response = contract.set_locker(accounts.bob).unwrap_err();
+    assert_eq!(err_response, PSP22VotesError::NotLocker);
+
+    set_caller(accounts.alice);
+    contract.set_locker(accounts.bob).unwrap();
+    assert_eq!(contract.locker(), Some(accounts.bob));
+
+    let err_response = contract.lock(accounts.alice, 100, 10).unwrap_err();
+    assert_eq!(err_response, PSP22VotesError::NotLocker);
+}
+
+#[ink::test]
+/// The locked balance cannot be transferred until the expired locks are removed
+fn lock_and_unlock_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract(accounts.alice, 1000);
+    contract.transfer(accounts.bob, 100, Vec::new()).unwrap();
+
+    let err_response = contract.lock(accounts.bob, 0, 1).unwrap_err();
+    assert_eq!(err_response, PSP22VotesError::InvalidLock);
+    let err_response = contract.lock(accounts.bob, 60, 0).unwrap_err();
+    assert_eq!(err_response, PSP22VotesError::InvalidLock);
+    let err_response = contract.lock(accounts.bob, 101, 1).unwrap_err();
+    assert_eq!(err_response, PSP22VotesError::InsufficientBalance);
+
+    // The locks overlap
+    contract.lock(accounts.bob, 60, 1).unwrap();
+    contract.lock(accounts.bob, 30, 3).unwrap();
+    assert_eq!(contract.locked_balance(accounts.bob), 60);
+
+    set_caller(accounts.bob);
+    let err_response = contract
+        .transfer(accounts.charlie, 50, Vec::new())
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        PSP22Error::Custom(String::from("Locked balance"))
+    );
+    contract.transfer(accounts.charlie, 40, Vec::new()).unwrap();
+
+    let err_response = contract.unlock(accounts.bob).unwrap_err();
+    assert_eq!(err_response, PSP22VotesError::NoExpiredLock);
+
+    // Anyone can remove the expired locks
+    ink::env::test::advance_block::<DefaultEnvironment>();
+    set_caller(accounts.charlie);
+    contract.unlock(accounts.bob).unwrap();
+    assert_eq!(
+        contract.locks(accounts.bob),
+        vec![Lock {
+            amount: 30,
+            until: 3,
+        }]
+    );
+    assert_eq!(contract.locked_balance(accounts.bob), 30);
+
+    set_caller(accounts.bob);
+    contract.transfer(accounts.charlie, 30, Vec::new()).unwrap();
+    let err_response = contract
+        .transfer(accounts.charlie, 1, Vec::new())
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        PSP22Error::Custom(String::from("Locked balance"))
+    );
+}
@@ -0,0 +1,143 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+#[openbrush::contract]
+pub mod timelock_controller {
+
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::vec::Vec,
+    };
+    use ink_governance::timelock_controller::*;
+    use openbrush::traits::Storage;
+
+    /// Emitted when the minimum delay for future operations is modified.
+    #[ink(event)]
+    pub struct MinDelayChange {
+        /// The old minimum delay.
+        pub old_delay: Timestamp,
+        /// The new minimum delay.
+        pub new_delay: Timestamp,
+    }
+
+    /// Emitted when a call is scheduled as part of operation `id`.
+    #[ink(event)]
+    pub struct CallScheduled {
+        /// The id of the operation.
+        #[ink(topic)]
+        pub id: OperationId,
+        /// The position of the call in the operation.
+        #[ink(topic)]
+        pub index: u32,
+        /// The transaction scheduled.
+        pub transaction: Transaction,
+        /// The operation that must be executed before this one.
+        pub predecessor: Option<OperationId>,
+        /// The delay after which the operation becomes ready.
+        pub delay: Timestamp,
+    }
+
+    /// Emitted when a call is performed as part of operation `id`.
+    #[ink(event)]
+    pub struct CallExecuted {
+        /// The id of the operation.
+        #[ink(topic)]
+        pub id: OperationId,
+        /// The position of the call in the operation.
+        #[ink(topic)]
+        pub index: u32,
+        /// The transaction executed.
+        pub transaction: Transaction,
+    }
+
+    /// Emitted when operation `id` is cancelled.
+    #[ink(event)]
+    pub struct Cancelled {
+        /// The id of the operation.
+        #[ink(topic)]
+        pub id: OperationId,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        access_control: access_control::Data,
+        #[storage_field]
+        timelock_controller: timelock_controller::Data,
+    }
+
+    impl AccessControl for Contract {}
+
+    impl TimelockController for Contract {}
+
+    // Override the internal methods
+    impl timelock_controller::Internal for Contract {
+        fn _emit_min_delay_change(&self, old_delay: Timestamp, new_delay: Timestamp) {
+            self.env().emit_event(MinDelayChange {
+                old_delay,
+                new_delay,
+            })
+        }
+        fn _emit_call_scheduled(
+            &self,
+            id: OperationId,
+            index: u32,
+            transaction: Transaction,
+            predecessor: Option<OperationId>,
+            delay: Timestamp,
+        ) {
+            self.env().emit_event(CallScheduled {
+                id,
+                index,
+                transaction,
+                predecessor,
+                delay,
+            })
+        }
+        fn _emit_call_executed(
+            &self,
+            id: OperationId,
+            index: u32,
+            transaction: Transaction,
+        ) {
+            self.env().emit_event(CallExecuted {
+                id,
+                index,
+                transaction,
+            })
+        }
+        fn _emit_cancelled(&self, id: OperationId) {
+            self.env().emit_event(Cancelled { id })
+        }
+    }
+
+    impl Contract {
+        /// Initialize the timelock with a minimum delay, the accounts that can propose and the
+        /// accounts that can execute operations, and an optional admin.
+        #[ink(constructor)]
+        pub fn new(
+            admin: Option<AccountId>,
+            min_delay: Timestamp,
+            proposers: Vec<AccountId>,
+            executors: Vec<AccountId>,
+        ) -> Self {
+            let mut instance = Self::default();
+
+            timelock_controller::Internal::_init_with_roles(
+                &mut instance,
+                admin,
+                min_delay,
+                proposers,
+                executors,
+            );
+
+            instance
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests;
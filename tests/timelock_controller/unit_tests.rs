@@ -0,0 +1,254 @@
+use ink::env::{
+    test::{
+        set_block_timestamp,
+        DefaultAccounts,
+        EmittedEvent,
+    },
+    DefaultEnvironment,
+};
+
+use crate::timelock_controller::*;
+use openbrush::{
+    test_utils::{
+        accounts,
+        change_caller,
+    },
+    traits::{
+        AccountId,
+        Hash,
+    },
+};
+
+use ink_governance::timelock_controller::*;
+
+type Event = <Contract as ::ink::reflect::ContractEventBase>::Type;
+
+const MIN_DELAY: Timestamp = 100;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    accounts()
+}
+
+fn set_caller(sender: AccountId) {
+    change_caller(sender)
+}
+
+fn build_contract() -> Contract {
+    let accounts = default_accounts();
+
+    set_caller(accounts.alice);
+
+    Contract::new(None, MIN_DELAY, vec![accounts.bob], vec![accounts.charlie])
+}
+
+fn decode_events(emittend_events: Vec<EmittedEvent>) -> Vec<Event> {
+    emittend_events
+        .into_iter()
+        .map(|event| {
+            <Event as scale::Decode>::decode(&mut &event.data[..]).expect("invalid data")
+        })
+        .collect()
+}
+
+fn schedule(contract: &mut Contract, predecessor: Option<OperationId>) -> OperationId {
+    let accounts = default_accounts();
+
+    set_caller(accounts.bob);
+    let transaction = Transaction::default();
+    let salt = Hash::default();
+    contract
+        .schedule(transaction.clone(), predecessor, salt, MIN_DELAY)
+        .unwrap();
+    contract.hash_operation(transaction, predecessor, salt)
+}
+
+#[ink::test]
+/// The constructor does its job
+fn contruction_works() {
+    let accounts = default_accounts();
+    let contract = build_contract();
+
+    let response = contract.get_min_delay();
+    assert_eq!(response, MIN_DELAY);
+
+    assert!(contract.has_role(PROPOSER_ROLE, accounts.bob));
+    assert!(contract.has_role(CANCELLER_ROLE, accounts.bob));
+    assert!(contract.has_role(EXECUTOR_ROLE, accounts.charlie));
+    assert!(!contract.has_role(PROPOSER_ROLE, accounts.charlie));
+}
+
+#[ink::test]
+fn schedule_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    // Charlie has not the proposer role
+    set_caller(accounts.charlie);
+    let err_response = contract
+        .schedule(Transaction::default(), None, Hash::default(), MIN_DELAY)
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        TimelockControllerError::AccessControlError(AccessControlError::MissingRole)
+    );
+
+    set_caller(accounts.bob);
+    let err_response = contract
+        .schedule(Transaction::default(), None, Hash::default(), MIN_DELAY - 1)
+        .unwrap_err();
+    assert_eq!(err_response, TimelockControllerError::InsufficientDelay);
+
+    let id = schedule(&mut contract, None);
+
+    assert!(contract.is_operation(id));
+    assert!(contract.is_operation_pending(id));
+    assert!(!contract.is_operation_ready(id));
+    assert_eq!(contract.get_timestamp(id), MIN_DELAY);
+
+    let emittend_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+    let decoded_events = decode_events(emittend_events);
+    if let Event::CallScheduled(CallScheduled {
+        id: operation_id,
+        index,
+        transaction,
+        predecessor,
+        delay,
+    }) = &decoded_events[1]
+    {
+        assert_eq!(operation_id, &id);
+        assert_eq!(index, &0);
+        assert_eq!(transaction, &Transaction::default());
+        assert_eq!(predecessor, &None);
+        assert_eq!(delay, &MIN_DELAY);
+    } else {
+        panic!("encountered unexpected event kind: expected a CallScheduled event")
+    }
+
+    let err_response = contract
+        .schedule(Transaction::default(), None, Hash::default(), MIN_DELAY)
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        TimelockControllerError::OperationAlreadyScheduled
+    );
+}
+
+#[ink::test]
+/// The timestamp of an operation can't overflow
+fn schedule_overflow_fails() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    set_block_timestamp::<DefaultEnvironment>(1);
+    set_caller(accounts.bob);
+    let err_response = contract
+        .schedule(
+            Transaction::default(),
+            None,
+            Hash::default(),
+            Timestamp::MAX,
+        )
+        .unwrap_err();
+    assert_eq!(err_response, TimelockControllerError::TimestampOverflow);
+}
+
+#[ink::test]
+fn schedule_batch_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+    let transactions = vec![Transaction::default(), Transaction::default()];
+
+    set_caller(accounts.bob);
+    contract
+        .schedule_batch(transactions.clone(), None, Hash::default(), MIN_DELAY)
+        .unwrap();
+
+    let id = contract.hash_operation_batch(transactions, None, Hash::default());
+    assert!(contract.is_operation_pending(id));
+
+    // The id of a batch is different from the id of a single transaction.
+    let single_id =
+        contract.hash_operation(Transaction::default(), None, Hash::default());
+    assert_ne!(id, single_id);
+    assert!(!contract.is_operation(single_id));
+}
+
+#[ink::test]
+fn cancel_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    let id = schedule(&mut contract, None);
+
+    set_caller(accounts.charlie);
+    let err_response = contract.cancel(id).unwrap_err();
+    assert_eq!(
+        err_response,
+        TimelockControllerError::AccessControlError(AccessControlError::MissingRole)
+    );
+
+    set_caller(accounts.bob);
+    contract.cancel(id).unwrap();
+    assert!(!contract.is_operation(id));
+
+    let err_response = contract.cancel(id).unwrap_err();
+    assert_eq!(
+        err_response,
+        TimelockControllerError::OperationCannotBeCanceled
+    );
+}
+
+#[ink::test]
+fn execute_checks_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    let predecessor = schedule(&mut contract, None);
+    let transaction = Transaction {
+        input: vec![1],
+        ..Default::default()
+    };
+    contract
+        .schedule(
+            transaction.clone(),
+            Some(predecessor),
+            Hash::default(),
+            MIN_DELAY,
+        )
+        .unwrap();
+
+    set_caller(accounts.bob);
+    let err_response = contract
+        .execute(transaction.clone(), Some(predecessor), Hash::default())
+        .unwrap_err();
+    assert_eq!(
+        err_response,
+        TimelockControllerError::AccessControlError(AccessControlError::MissingRole)
+    );
+
+    set_caller(accounts.charlie);
+    let err_response = contract
+        .execute(transaction.clone(), Some(predecessor), Hash::default())
+        .unwrap_err();
+    assert_eq!(err_response, TimelockControllerError::OperationIsNotReady);
+
+    set_block_timestamp::<DefaultEnvironment>(MIN_DELAY);
+    let err_response = contract
+        .execute(transaction, Some(predecessor), Hash::default())
+        .unwrap_err();
+    assert_eq!(err_response, TimelockControllerError::MissingDependency);
+
+    // In this case since we are in an off-chain envoriment we can't test a successfull
+    // execution of the operation.(cross-contract calls are not supported)
+}
+
+#[ink::test]
+fn update_delay_works() {
+    let accounts = default_accounts();
+    let mut contract = build_contract();
+
+    // Only the timelock itself can update the delay.
+    set_caller(accounts.bob);
+    let err_response = contract.update_delay(0).unwrap_err();
+    assert_eq!(err_response, TimelockControllerError::CallerMustBeTimeLock);
+}